@@ -14,3 +14,12 @@ cargo +nightly clippy --no-default-features --features libm
 cargo +nightly clippy --no-default-features --features serde,libm
 cargo +nightly clippy --no-default-features --features serde,std,libm
 
+cargo +nightly clippy --no-default-features --features arbitrary
+cargo +nightly clippy --no-default-features --features proptest
+cargo +nightly clippy --no-default-features --features quickcheck
+cargo +nightly clippy --no-default-features --features rand
+cargo +nightly clippy --no-default-features --features rand_distr
+cargo +nightly clippy --no-default-features --features borsh_schema
+cargo +nightly clippy --no-default-features --features rkyv
+cargo +nightly clippy --no-default-features --features defmt
+cargo +nightly clippy --no-default-features --features ufmt
@@ -0,0 +1 @@
+//! The tests are in `tests/`, as their dependencies require a newer Rust than the MSRV of `typed_floats`.
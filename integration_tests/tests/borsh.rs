@@ -0,0 +1,132 @@
+#![cfg(feature = "borsh")]
+
+use borsh::io::ErrorKind;
+use typed_floats::*;
+
+macro_rules! impl_borsh_test {
+    ($test:ident, $type:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                for x in tf64::get_test_values() {
+                    let bytes = x.to_bits().to_le_bytes();
+                    let decoded = borsh::from_slice::<$type<f64>>(&bytes);
+
+                    match $type::<f64>::new(x) {
+                        Ok(typed) => {
+                            let encoded = borsh::to_vec(&typed).expect("valid value");
+                            assert_eq!(encoded, bytes);
+
+                            let decoded = decoded.expect("valid value");
+                            assert_eq!(decoded.get().to_bits(), x.to_bits());
+                        }
+                        Err(reason) => {
+                            let error = decoded.expect_err("invalid value");
+                            assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+                            #[cfg(feature = "std")]
+                            assert_eq!(
+                                error
+                                    .get_ref()
+                                    .and_then(|e| e.downcast_ref::<InvalidNumber>()),
+                                Some(&reason)
+                            );
+
+                            #[cfg(not(feature = "std"))]
+                            assert_eq!(error.to_string(), reason.to_string());
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                for x in tf32::get_test_values() {
+                    let bytes = x.to_bits().to_le_bytes();
+                    let decoded = borsh::from_slice::<$type<f32>>(&bytes);
+
+                    match $type::<f32>::new(x) {
+                        Ok(typed) => {
+                            let encoded = borsh::to_vec(&typed).expect("valid value");
+                            assert_eq!(encoded, bytes);
+
+                            let decoded = decoded.expect("valid value");
+                            assert_eq!(decoded.get().to_bits(), x.to_bits());
+                        }
+                        Err(reason) => {
+                            let error = decoded.expect_err("invalid value");
+                            assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+                            #[cfg(feature = "std")]
+                            assert_eq!(
+                                error
+                                    .get_ref()
+                                    .and_then(|e| e.downcast_ref::<InvalidNumber>()),
+                                Some(&reason)
+                            );
+
+                            #[cfg(not(feature = "std"))]
+                            assert_eq!(error.to_string(), reason.to_string());
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_borsh_test!(non_nan, NonNaN);
+impl_borsh_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_borsh_test!(non_nan_finite, NonNaNFinite);
+impl_borsh_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_borsh_test!(positive, Positive);
+impl_borsh_test!(negative, Negative);
+impl_borsh_test!(positive_finite, PositiveFinite);
+impl_borsh_test!(negative_finite, NegativeFinite);
+impl_borsh_test!(strictly_positive, StrictlyPositive);
+impl_borsh_test!(strictly_negative, StrictlyNegative);
+impl_borsh_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_borsh_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_struct() {
+    #[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, PartialEq)]
+    struct Snapshot {
+        position: NonNaNFinite,
+        mass: StrictlyPositiveFinite<f32>,
+    }
+
+    let snapshot = Snapshot {
+        position: NonNaNFinite::<f64>::new(-3.5).expect("valid value"),
+        mass: StrictlyPositiveFinite::<f32>::new(2.0).expect("valid value"),
+    };
+
+    let encoded = borsh::to_vec(&snapshot).expect("valid value");
+    assert_eq!(encoded.len(), 12);
+
+    let decoded: Snapshot = borsh::from_slice(&encoded).expect("valid value");
+    assert_eq!(decoded, snapshot);
+
+    // A negative mass is rejected
+    let mut invalid = encoded;
+    if let Some(byte) = invalid.last_mut() {
+        *byte |= 0x80;
+    }
+    assert!(borsh::from_slice::<Snapshot>(&invalid).is_err());
+}
+
+#[cfg(feature = "borsh_schema")]
+#[test]
+fn test_schema() {
+    use borsh::schema::{BorshSchemaContainer, Definition};
+
+    let container = BorshSchemaContainer::for_type::<Positive<f32>>();
+
+    assert_eq!(container.declaration(), "Positive<f32>");
+    assert_eq!(
+        container.get_definition("Positive<f32>"),
+        Some(&Definition::Primitive(4))
+    );
+}
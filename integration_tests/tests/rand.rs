@@ -0,0 +1,154 @@
+#![cfg(feature = "rand")]
+#![allow(clippy::unwrap_used)]
+
+use rand::distr::{Distribution, StandardUniform};
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use typed_floats::*;
+
+macro_rules! impl_rand_test {
+    ($test:ident, $type:ident, $standard:expr) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn standard() {
+                let mut rng = StdRng::seed_from_u64(0);
+
+                for _ in 0..10_000 {
+                    let x: $type<f64> = rng.random();
+                    assert!($standard.contains(&x.get()), "{x:?}");
+
+                    let x: $type<f32> = rng.random();
+                    assert!($standard.contains(&f64::from(x.get())), "{x:?}");
+                }
+            }
+
+            #[test]
+            fn bit_patterns() {
+                let mut rng = StdRng::seed_from_u64(0);
+
+                let values: Vec<$type<f64>> =
+                    BitPatterns.sample_iter(&mut rng).take(10_000).collect();
+
+                for x in &values {
+                    assert!($type::<f64>::new(x.get()).is_ok(), "{x:?}");
+                }
+
+                // Values of all magnitudes are generated
+                assert!(values.iter().any(|x| x.get().abs() < 1e-100));
+                assert!(values.iter().any(|x| x.get().abs() > 1e100));
+
+                for _ in 0..10_000 {
+                    let x: $type<f32> = BitPatterns.sample(&mut rng);
+                    assert!($type::<f32>::new(x.get()).is_ok(), "{x:?}");
+                }
+            }
+
+            #[test]
+            fn range() {
+                let mut rng = StdRng::seed_from_u64(0);
+
+                let values = tf64::get_test_values()
+                    .into_iter()
+                    .filter_map(|x| $type::<f64>::new(x).ok())
+                    .filter(|x| x.is_finite())
+                    .collect::<Vec<_>>();
+
+                for &a in &values {
+                    for &b in &values {
+                        if a < b && (b.get() - a.get()).is_finite() {
+                            for _ in 0..100 {
+                                // As with `f64`, `b` may be generated because of rounding
+                                let x: $type<f64> = rng.random_range(a..b);
+                                assert!(a <= x && x <= b, "{a:?}..{b:?} : {x:?}");
+                            }
+                        }
+
+                        if a <= b && (b.get() - a.get()).is_finite() {
+                            for _ in 0..100 {
+                                let x: $type<f64> = rng.random_range(a..=b);
+                                assert!(a <= x && x <= b, "{a:?}..={b:?} : {x:?}");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_rand_test!(non_nan, NonNaN, (0.0..1.0));
+impl_rand_test!(non_zero_non_nan, NonZeroNonNaN, (f64::MIN_POSITIVE..=1.0));
+impl_rand_test!(non_nan_finite, NonNaNFinite, (0.0..1.0));
+impl_rand_test!(
+    non_zero_non_nan_finite,
+    NonZeroNonNaNFinite,
+    (f64::MIN_POSITIVE..=1.0)
+);
+impl_rand_test!(positive, Positive, (0.0..1.0));
+impl_rand_test!(negative, Negative, (-1.0..=0.0));
+impl_rand_test!(positive_finite, PositiveFinite, (0.0..1.0));
+impl_rand_test!(negative_finite, NegativeFinite, (-1.0..=0.0));
+impl_rand_test!(
+    strictly_positive,
+    StrictlyPositive,
+    (f64::MIN_POSITIVE..=1.0)
+);
+impl_rand_test!(
+    strictly_negative,
+    StrictlyNegative,
+    (-1.0..=-f64::MIN_POSITIVE)
+);
+impl_rand_test!(
+    strictly_positive_finite,
+    StrictlyPositiveFinite,
+    (f64::MIN_POSITIVE..=1.0)
+);
+impl_rand_test!(
+    strictly_negative_finite,
+    StrictlyNegativeFinite,
+    (-1.0..=-f64::MIN_POSITIVE)
+);
+
+#[test]
+fn test_range_with_zero() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    // The zero is never generated when rejected
+    let a = NonZeroNonNaNFinite::<f64>::new(-f64::from_bits(2)).unwrap();
+    let b = NonZeroNonNaNFinite::<f64>::new(f64::from_bits(2)).unwrap();
+
+    for _ in 0..1_000 {
+        let x: NonZeroNonNaNFinite = rng.random_range(a..=b);
+        assert!(x != 0.0);
+    }
+
+    // `-0.0` is generated instead of `+0.0`
+    let a = NegativeFinite::<f64>::new(-1.0).unwrap();
+    let b = NegativeFinite::<f64>::new(-0.0).unwrap();
+
+    for _ in 0..1_000 {
+        let x: NegativeFinite = rng.random_range(a..=b);
+        assert!(x.is_sign_negative());
+    }
+}
+
+#[test]
+#[should_panic(expected = "NonFinite")]
+fn test_range_infinite() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let a = Positive::<f64>::new(0.0).unwrap();
+    let b = Positive::<f64>::new(f64::INFINITY).unwrap();
+
+    let _: Positive = rng.random_range(a..b);
+}
+
+#[test]
+fn test_standard_is_the_distribution() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let x: StrictlyPositiveFinite = StandardUniform.sample(&mut rng);
+    assert!(x > 0.0 && x <= 1.0);
+}
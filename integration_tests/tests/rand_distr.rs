@@ -0,0 +1,110 @@
+#![cfg(feature = "rand_distr")]
+
+use rand::distr::Distribution;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Exp, Exp1, Gamma, Normal, StandardNormal};
+use typed_floats::*;
+
+#[test]
+fn test_exp() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for lambda in tf64::get_test_values() {
+        if let Ok(typed) = StrictlyPositiveFinite::<f64>::new(lambda) {
+            let exp = Exp::from_typed(typed);
+            assert_eq!(exp, Exp::new(lambda).expect("valid rate"));
+
+            for _ in 0..1_000 {
+                let _: Positive = exp.sample(&mut rng);
+            }
+        }
+    }
+
+    // Limit cases that are not accepted by `from_typed`
+    for lambda in [0.0, f64::INFINITY] {
+        let exp = Exp::new(lambda).expect("valid rate");
+
+        for _ in 0..1_000 {
+            let _: Positive = exp.sample(&mut rng);
+        }
+    }
+
+    let exp = Exp::from_typed(StrictlyPositiveFinite::<f32>::new(2.0).expect("valid rate"));
+    for _ in 0..1_000 {
+        let _: Positive<f32> = exp.sample(&mut rng);
+    }
+}
+
+#[test]
+fn test_normal() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for mean in tf64::get_test_values() {
+        for std_dev in tf64::get_test_values() {
+            if let (Ok(typed_mean), Ok(typed_std_dev)) = (
+                NonNaNFinite::<f64>::new(mean),
+                PositiveFinite::<f64>::new(std_dev),
+            ) {
+                let normal = Normal::from_typed(typed_mean, typed_std_dev);
+                assert_eq!(
+                    normal,
+                    Normal::new(mean, std_dev).expect("valid parameters")
+                );
+
+                for _ in 0..100 {
+                    let x: f64 = normal.sample(&mut rng);
+                    assert!(!x.is_nan());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gamma() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for shape in tf64::get_test_values() {
+        for scale in tf64::get_test_values() {
+            if let (Ok(typed_shape), Ok(typed_scale)) = (
+                StrictlyPositiveFinite::<f64>::new(shape),
+                StrictlyPositiveFinite::<f64>::new(scale),
+            ) {
+                let gamma = Gamma::from_typed(typed_shape, typed_scale);
+
+                for _ in 0..100 {
+                    let _: Positive = gamma.sample(&mut rng);
+                }
+            }
+        }
+    }
+
+    // Limit cases that are not accepted by `from_typed`
+    for shape in [1.0, f64::INFINITY] {
+        let gamma = Gamma::new(shape, f64::INFINITY).expect("valid parameters");
+
+        for _ in 0..1_000 {
+            let _: Positive = gamma.sample(&mut rng);
+        }
+    }
+}
+
+#[test]
+fn test_standard() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for _ in 0..10_000 {
+        let x: Positive = Exp1.sample(&mut rng);
+        assert!(x >= 0.0);
+
+        let x: Positive<f32> = Exp1.sample(&mut rng);
+        assert!(x >= 0.0);
+
+        let x: NonNaNFinite = StandardNormal.sample(&mut rng);
+        assert!(x.is_finite());
+
+        let x: NonNaNFinite<f32> = StandardNormal.sample(&mut rng);
+        assert!(x.is_finite());
+    }
+}
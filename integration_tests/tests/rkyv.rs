@@ -0,0 +1,107 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use typed_floats::*;
+
+macro_rules! impl_rkyv_test {
+    ($test:ident, $type:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                for x in tf64::get_test_values() {
+                    let bytes = x.to_bits().to_le_bytes();
+                    let accessed = rkyv::access::<rkyv::Archived<$type<f64>>, Error>(&bytes);
+
+                    match $type::<f64>::new(x) {
+                        Ok(typed) => {
+                            let encoded = rkyv::to_bytes::<Error>(&typed).expect("valid value");
+                            assert_eq!(encoded.as_slice(), bytes);
+
+                            let accessed = accessed.expect("valid value");
+                            assert_eq!(accessed.to_native().get().to_bits(), x.to_bits());
+
+                            let decoded = rkyv::from_bytes::<$type<f64>, Error>(&encoded)
+                                .expect("valid value");
+                            assert_eq!(decoded.get().to_bits(), x.to_bits());
+                        }
+                        Err(_) => {
+                            assert!(accessed.is_err());
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                for x in tf32::get_test_values() {
+                    let bytes = x.to_bits().to_le_bytes();
+                    let accessed = rkyv::access::<rkyv::Archived<$type<f32>>, Error>(&bytes);
+
+                    match $type::<f32>::new(x) {
+                        Ok(typed) => {
+                            let encoded = rkyv::to_bytes::<Error>(&typed).expect("valid value");
+                            assert_eq!(encoded.as_slice(), bytes);
+
+                            let accessed = accessed.expect("valid value");
+                            assert_eq!(accessed.to_native().get().to_bits(), x.to_bits());
+
+                            let decoded = rkyv::from_bytes::<$type<f32>, Error>(&encoded)
+                                .expect("valid value");
+                            assert_eq!(decoded.get().to_bits(), x.to_bits());
+                        }
+                        Err(_) => {
+                            assert!(accessed.is_err());
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_rkyv_test!(non_nan, NonNaN);
+impl_rkyv_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_rkyv_test!(non_nan_finite, NonNaNFinite);
+impl_rkyv_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_rkyv_test!(positive, Positive);
+impl_rkyv_test!(negative, Negative);
+impl_rkyv_test!(positive_finite, PositiveFinite);
+impl_rkyv_test!(negative_finite, NegativeFinite);
+impl_rkyv_test!(strictly_positive, StrictlyPositive);
+impl_rkyv_test!(strictly_negative, StrictlyNegative);
+impl_rkyv_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_rkyv_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_struct() {
+    #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize, Debug, PartialEq)]
+    struct Snapshot {
+        position: NonNaNFinite,
+        mass: StrictlyPositiveFinite<f32>,
+    }
+
+    let snapshot = Snapshot {
+        position: NonNaNFinite::<f64>::new(-3.5).expect("valid value"),
+        mass: StrictlyPositiveFinite::<f32>::new(2.0).expect("valid value"),
+    };
+
+    let encoded = rkyv::to_bytes::<Error>(&snapshot).expect("valid value");
+
+    let archived = rkyv::access::<ArchivedSnapshot, Error>(&encoded).expect("valid value");
+    assert_eq!(archived.position, snapshot.position);
+    assert_eq!(archived.mass, snapshot.mass);
+
+    let decoded = rkyv::from_bytes::<Snapshot, Error>(&encoded).expect("valid value");
+    assert_eq!(decoded, snapshot);
+
+    // A negative mass is rejected
+    let mut invalid = encoded;
+    let offset = core::mem::offset_of!(ArchivedSnapshot, mass);
+    if let Some(byte) = invalid.get_mut(offset + 3) {
+        *byte |= 0x80;
+    }
+    assert!(rkyv::access::<ArchivedSnapshot, Error>(&invalid).is_err());
+    assert!(rkyv::from_bytes::<Snapshot, Error>(&invalid).is_err());
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "serde")]
+
+use typed_floats::*;
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Strict {
+    #[serde(with = "typed_floats::serde_f32::strict")]
+    a: Positive<f32>,
+    b: u8,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Exact {
+    #[serde(with = "typed_floats::serde_f32::exact")]
+    a: NonNaNFinite<f32>,
+    b: u8,
+}
+
+// `postcard` is not self-describing, the `f32` must be read back as an `f32`
+#[test]
+fn test_serde_f32_postcard() {
+    for x in tf32::get_test_values() {
+        if let Ok(a) = Positive::<f32>::new(x) {
+            let value = Strict { a, b: 42 };
+            let bytes = postcard::to_allocvec(&value).expect("serializable");
+            let decoded: Strict = postcard::from_bytes(&bytes).expect("valid bytes");
+            assert_eq!(decoded.a.get().to_bits(), a.get().to_bits());
+            assert_eq!(decoded.b, 42);
+        }
+
+        if let Ok(a) = NonNaNFinite::<f32>::new(x) {
+            let value = Exact { a, b: 42 };
+            let bytes = postcard::to_allocvec(&value).expect("serializable");
+            let decoded: Exact = postcard::from_bytes(&bytes).expect("valid bytes");
+            assert_eq!(decoded.a.get().to_bits(), a.get().to_bits());
+            assert_eq!(decoded.b, 42);
+        }
+    }
+
+    // The values are still checked
+    let bytes = postcard::to_allocvec(&(-1.0f32, 42u8)).expect("serializable");
+    let error = postcard::from_bytes::<Strict>(&bytes).expect_err("negative value");
+    assert_eq!(error, postcard::Error::SerdeDeCustom);
+}
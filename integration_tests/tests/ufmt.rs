@@ -0,0 +1,224 @@
+#![cfg(feature = "ufmt")]
+#![allow(clippy::float_cmp)]
+
+use typed_floats::*;
+
+fn display<T: ufmt::uDisplay + ?Sized>(value: &T) -> String {
+    let mut s = String::new();
+    ufmt::uwrite!(s, "{}", value).expect("infallible");
+    s
+}
+
+fn debug<T: ufmt::uDebug + ?Sized>(value: &T) -> String {
+    let mut s = String::new();
+    ufmt::uwrite!(s, "{:?}", value).expect("infallible");
+    s
+}
+
+/// The printed value must be parsed back to the same value, up to the printed precision
+fn assert_close(printed: &str, expected: f64, precision: f64) {
+    // The mantissa is compared separately as the rounding can overflow (`1.79769313486232e308`)
+    let (mantissa, exponent) = printed.split_once('e').map_or((printed, 0), |(m, e)| {
+        (m, e.parse::<i32>().expect("valid exponent"))
+    });
+    let parsed: f64 = mantissa.parse().expect("valid float");
+
+    assert_eq!(parsed.is_sign_negative(), expected.is_sign_negative());
+
+    if expected.is_infinite() || expected == 0.0 {
+        assert_eq!(parsed, expected, "{printed}");
+    } else {
+        // In two steps to avoid overflows
+        let expected = expected / 10f64.powi(exponent / 2) / 10f64.powi(exponent - exponent / 2);
+        let error = ((parsed - expected) / expected).abs();
+        assert!(error <= precision, "{printed} != {expected}");
+    }
+}
+
+macro_rules! impl_ufmt_test {
+    ($test:ident, $type:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                for x in tf64::get_test_values() {
+                    if let Ok(typed) = $type::<f64>::new(x) {
+                        let printed = display(&typed);
+                        assert_close(&printed, x, 1e-14);
+
+                        assert_eq!(debug(&typed), format!("{}({printed})", stringify!($type)));
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                for x in tf32::get_test_values() {
+                    if let Ok(typed) = $type::<f32>::new(x) {
+                        let printed = display(&typed);
+                        assert_close(&printed, f64::from(x), 5e-6);
+
+                        assert_eq!(debug(&typed), format!("{}({printed})", stringify!($type)));
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_ufmt_test!(non_nan, NonNaN);
+impl_ufmt_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_ufmt_test!(non_nan_finite, NonNaNFinite);
+impl_ufmt_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_ufmt_test!(positive, Positive);
+impl_ufmt_test!(negative, Negative);
+impl_ufmt_test!(positive_finite, PositiveFinite);
+impl_ufmt_test!(negative_finite, NegativeFinite);
+impl_ufmt_test!(strictly_positive, StrictlyPositive);
+impl_ufmt_test!(strictly_negative, StrictlyNegative);
+impl_ufmt_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_ufmt_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_display() {
+    let cases: &[(f64, &str)] = &[
+        (0.0, "0"),
+        (-0.0, "-0"),
+        (1.0, "1"),
+        (-2.5, "-2.5"),
+        (0.1 + 0.2, "0.3"),
+        (123_456.789, "123456.789"),
+        (0.000_012_5, "0.0000125"),
+        (1.5e-7, "1.5e-7"),
+        (1e15, "1e15"),
+        (123_456_789_012_345.0, "123456789012345"),
+        (f64::MAX, "1.79769313486232e308"),
+        (f64::MIN_POSITIVE, "2.2250738585072e-308"),
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+    ];
+
+    for &(x, expected) in cases {
+        assert_eq!(
+            display(&NonNaN::<f64>::new(x).expect("valid value")),
+            expected
+        );
+    }
+
+    let cases: &[(f32, &str)] = &[
+        (0.1, "0.1"),
+        (-3.75, "-3.75"),
+        (f32::MAX, "3.40282e38"),
+        (f32::INFINITY, "inf"),
+    ];
+
+    for &(x, expected) in cases {
+        assert_eq!(
+            display(&NonNaN::<f32>::new(x).expect("valid value")),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(display(&InvalidNumber::NaN), InvalidNumber::NaN.to_string());
+    assert_eq!(debug(&InvalidNumber::Zero), "Zero");
+
+    for s in ["", "abc", "-1", "NaN"] {
+        let error = s.parse::<Positive>().expect_err("invalid value");
+        assert_eq!(display(&error), error.to_string());
+    }
+
+    let error = "".parse::<Positive>().expect_err("invalid value");
+    assert_eq!(debug(&error), "ParseFloatError(Empty)");
+
+    let error = "-1".parse::<Positive>().expect_err("invalid value");
+    assert_eq!(debug(&error), "InvalidNumber(Negative)");
+
+    let error = Positive::<f64>::from_str_exact("1e400").expect_err("invalid value");
+    assert_eq!(display(&error), error.to_string());
+    assert_eq!(debug(&error), "Overflow");
+
+    let error = Positive::<f64>::from_str_exact("-1").expect_err("invalid value");
+    assert_eq!(display(&error), error.to_string());
+    assert_eq!(debug(&error), "FromStrError(InvalidNumber(Negative))");
+}
+
+/// Rewrites a printed value as `format!("{:e}")` would, without the trailing zeros
+fn to_scientific(printed: &str) -> String {
+    let (sign, printed) = printed
+        .strip_prefix('-')
+        .map_or(("", printed), |printed| ("-", printed));
+    let (mantissa, exponent) = printed.split_once('e').map_or((printed, 0), |(m, e)| {
+        (m, e.parse::<i32>().expect("valid exponent"))
+    });
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let all_digits = format!("{integer}{fraction}");
+    let digits = all_digits.trim_start_matches('0');
+    let leading_zeros = i32::try_from(all_digits.len() - digits.len()).expect("small length");
+    let exponent =
+        exponent + i32::try_from(integer.len()).expect("small length") - 1 - leading_zeros;
+    let digits = digits.trim_end_matches('0');
+
+    if digits.len() > 1 {
+        format!("{sign}{}.{}e{exponent}", &digits[..1], &digits[1..])
+    } else {
+        format!("{sign}{digits}e{exponent}")
+    }
+}
+
+/// `format!("{:.*e}")` with `digits` significant digits, without the trailing zeros
+fn std_scientific(value: impl core::fmt::LowerExp, digits: usize) -> String {
+    let printed = format!("{:.*e}", digits - 1, value);
+    let (mantissa, exponent) = printed.split_once('e').expect("scientific notation");
+    let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+
+    format!("{mantissa}e{exponent}")
+}
+
+#[test]
+fn test_digits() {
+    let significands = [
+        1.0,
+        1.234_567_890_123_456_7,
+        9.999_999_999_999_999,
+        3.333_333_333_333_333_5,
+        5.0,
+    ];
+
+    for exponent in -324..=308 {
+        for significand in significands {
+            let x: f64 = format!("{significand}e{exponent}")
+                .parse()
+                .expect("valid float");
+            if let Ok(x) = NonZeroNonNaNFinite::<f64>::new(x) {
+                assert_eq!(to_scientific(&display(&x)), std_scientific(x.get(), 15));
+                assert_eq!(to_scientific(&display(&-x)), std_scientific(-x.get(), 15));
+            }
+        }
+    }
+
+    for exponent in -45..=38 {
+        for significand in significands {
+            let x: f32 = format!("{significand}e{exponent}")
+                .parse()
+                .expect("valid float");
+            if let Ok(x) = NonZeroNonNaNFinite::<f32>::new(x) {
+                assert_eq!(to_scientific(&display(&x)), std_scientific(x.get(), 6));
+            }
+        }
+    }
+
+    // Exact ties are rounded to even
+    assert_eq!(
+        display(&NonNaN::<f64>::new(1_234_567_890_123_455.0).expect("valid value")),
+        "1.23456789012346e15"
+    );
+    assert_eq!(
+        display(&NonNaN::<f64>::new(1_234_567_890_123_465.0).expect("valid value")),
+        "1.23456789012346e15"
+    );
+}
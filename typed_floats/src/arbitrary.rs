@@ -0,0 +1,63 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::generators::{
+    f32_edge_values, f32_from_bits, f64_edge_values, f64_from_bits, EDGE_DENOMINATOR,
+};
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+macro_rules! impl_arbitrary {
+    ($type:ident) => {
+        impl_arbitrary!($type, f64, u64, f64_edge_values, f64_from_bits);
+        impl_arbitrary!($type, f32, u32, f32_edge_values, f32_from_bits);
+    };
+    ($type:ident, $float:ident, $bits:ident, $edge_values:ident, $from_bits:ident) => {
+        impl<'a> Arbitrary<'a> for $type<$float> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                if u.ratio(1, EDGE_DENOMINATOR)? {
+                    let index = u.choose_index($edge_values::<Self>().count())?;
+
+                    if let Some(value) = $edge_values::<Self>().nth(index) {
+                        return Ok(value);
+                    }
+                }
+
+                let value = $from_bits(
+                    $bits::arbitrary(u)?,
+                    crate::$type::accept_infinity(),
+                    crate::$type::accept_zero(),
+                    crate::$type::accept_positive(),
+                    crate::$type::accept_negative(),
+                );
+
+                // # Safety
+                // This is safe because the bits have been mapped onto a valid value.
+                Ok(unsafe { Self::new_unchecked(value) })
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                arbitrary::size_hint::and(
+                    <u8 as Arbitrary>::size_hint(depth),
+                    <$bits as Arbitrary>::size_hint(depth),
+                )
+            }
+        }
+    };
+}
+
+impl_arbitrary!(NonNaN);
+impl_arbitrary!(NonZeroNonNaN);
+impl_arbitrary!(NonNaNFinite);
+impl_arbitrary!(NonZeroNonNaNFinite);
+impl_arbitrary!(Positive);
+impl_arbitrary!(Negative);
+impl_arbitrary!(PositiveFinite);
+impl_arbitrary!(NegativeFinite);
+impl_arbitrary!(StrictlyPositive);
+impl_arbitrary!(StrictlyNegative);
+impl_arbitrary!(StrictlyPositiveFinite);
+impl_arbitrary!(StrictlyNegativeFinite);
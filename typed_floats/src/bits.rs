@@ -0,0 +1,178 @@
+use const_fn::const_fn;
+
+use crate::kind::FloatKind;
+use crate::types::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+macro_rules! impl_bits_float {
+    ($type:ident, $float:ident, $bits:ident, $bytes:literal) => {
+        impl $type<$float> {
+            /// Returns the raw transmutation of the value, like the primitive
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_bits(self) -> $bits {
+                self.get().to_bits()
+            }
+
+            /// Creates a value from its raw transmutation, like the primitive
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_bits(bits: $bits) -> Result<Self, InvalidNumber> {
+                Self::new($float::from_bits(bits))
+            }
+
+            /// Returns the memory representation of the value as a byte array in little-endian byte order
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                self.get().to_le_bytes()
+            }
+
+            /// Returns the memory representation of the value as a byte array in big-endian (network) byte order
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                self.get().to_be_bytes()
+            }
+
+            /// Returns the memory representation of the value as a byte array in native byte order
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_ne_bytes(self) -> [u8; $bytes] {
+                self.get().to_ne_bytes()
+            }
+
+            /// Creates a value from its representation as a byte array in little-endian byte order
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_le_bytes(bytes: [u8; $bytes]) -> Result<Self, InvalidNumber> {
+                Self::new($float::from_le_bytes(bytes))
+            }
+
+            /// Creates a value from its representation as a byte array in big-endian (network) byte order
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_be_bytes(bytes: [u8; $bytes]) -> Result<Self, InvalidNumber> {
+                Self::new($float::from_be_bytes(bytes))
+            }
+
+            /// Creates a value from its representation as a byte array in native byte order
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_ne_bytes(bytes: [u8; $bytes]) -> Result<Self, InvalidNumber> {
+                Self::new($float::from_ne_bytes(bytes))
+            }
+
+            /// Returns a key whose unsigned order matches the order of the values
+            ///
+            /// The sign bit is flipped for the positive values and all the bits are flipped for the negative values.
+            /// `-0.0` gives the same key as `0.0` when the type accepts both, as they are equal.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let a = NonNaN::<f64>::new(-1.0).unwrap();
+            /// let b = NonNaN::<f64>::new(2.0).unwrap();
+            ///
+            /// assert!(a.to_ordered_bits() < b.to_ordered_bits());
+            /// ```
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_ordered_bits(self) -> $bits {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                let kind = FloatKind::$type;
+                let both_zeros =
+                    kind.accept_zero() && kind.accept_positive() && kind.accept_negative();
+
+                let mut bits = self.to_bits();
+
+                if both_zeros && bits == SIGN {
+                    bits = 0;
+                }
+
+                if bits & SIGN == 0 {
+                    bits | SIGN
+                } else {
+                    !bits
+                }
+            }
+
+            /// Creates a value from a key returned by `to_ordered_bits`
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_ordered_bits(key: $bits) -> Result<Self, InvalidNumber> {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                let bits = if key & SIGN == 0 { !key } else { key & !SIGN };
+
+                Self::try_from_bits(bits)
+            }
+
+            /// Returns the key of `to_ordered_bits` as a byte array in big-endian byte order,
+            /// so the lexicographic order of the bytes matches the order of the values
+            #[inline]
+            #[must_use]
+            #[const_fn("1.83")]
+            pub const fn to_ordered_bytes(self) -> [u8; $bytes] {
+                self.to_ordered_bits().to_be_bytes()
+            }
+
+            /// Creates a value from the bytes returned by `to_ordered_bytes`
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn try_from_ordered_bytes(
+                bytes: [u8; $bytes],
+            ) -> Result<Self, InvalidNumber> {
+                Self::try_from_ordered_bits($bits::from_be_bytes(bytes))
+            }
+        }
+    };
+}
+
+macro_rules! impl_bits {
+    ($type:ident) => {
+        impl_bits_float!($type, f64, u64, 8);
+        impl_bits_float!($type, f32, u32, 4);
+    };
+}
+
+impl_bits!(NonNaN);
+impl_bits!(NonZeroNonNaN);
+impl_bits!(NonNaNFinite);
+impl_bits!(NonZeroNonNaNFinite);
+impl_bits!(Positive);
+impl_bits!(Negative);
+impl_bits!(PositiveFinite);
+impl_bits!(NegativeFinite);
+impl_bits!(StrictlyPositive);
+impl_bits!(StrictlyNegative);
+impl_bits!(StrictlyPositiveFinite);
+impl_bits!(StrictlyNegativeFinite);
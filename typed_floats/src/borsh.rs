@@ -0,0 +1,79 @@
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::types::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+/// The error returned when the decoded value is not accepted by the type.
+/// Without `std`, the reason can only be kept as a message.
+#[cfg_attr(not(feature = "std"), allow(clippy::needless_pass_by_value))]
+fn invalid_data(error: InvalidNumber) -> Error {
+    #[cfg(feature = "std")]
+    return Error::new(ErrorKind::InvalidData, error);
+
+    #[cfg(not(feature = "std"))]
+    return Error::new(
+        ErrorKind::InvalidData,
+        alloc::string::ToString::to_string(&error),
+    );
+}
+
+macro_rules! impl_borsh {
+    ($type:ident) => {
+        impl_borsh!($type, f64, u64, 8);
+        impl_borsh!($type, f32, u32, 4);
+    };
+    ($type:ident, $float:ident, $bits:ident, $size:literal) => {
+        impl BorshSerialize for $type<$float> {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.get().serialize(writer)
+            }
+        }
+
+        impl BorshDeserialize for $type<$float> {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                // Read as bits as `borsh` rejects `NaN` with its own error
+                let value = $float::from_bits($bits::deserialize_reader(reader)?);
+
+                Self::new(value).map_err(invalid_data)
+            }
+        }
+
+        #[cfg(feature = "borsh_schema")]
+        impl borsh::BorshSchema for $type<$float> {
+            #[inline]
+            fn add_definitions_recursively(
+                definitions: &mut alloc::collections::BTreeMap<
+                    borsh::schema::Declaration,
+                    borsh::schema::Definition,
+                >,
+            ) {
+                let definition = borsh::schema::Definition::Primitive($size);
+                borsh::schema::add_definition(Self::declaration(), definition, definitions);
+            }
+
+            #[inline]
+            fn declaration() -> borsh::schema::Declaration {
+                concat!(stringify!($type), "<", stringify!($float), ">").into()
+            }
+        }
+    };
+}
+
+impl_borsh!(NonNaN);
+impl_borsh!(NonZeroNonNaN);
+impl_borsh!(NonNaNFinite);
+impl_borsh!(NonZeroNonNaNFinite);
+impl_borsh!(Positive);
+impl_borsh!(Negative);
+impl_borsh!(PositiveFinite);
+impl_borsh!(NegativeFinite);
+impl_borsh!(StrictlyPositive);
+impl_borsh!(StrictlyNegative);
+impl_borsh!(StrictlyPositiveFinite);
+impl_borsh!(StrictlyNegativeFinite);
@@ -0,0 +1,177 @@
+use const_fn::const_fn;
+
+use crate::kind::FloatKind;
+use crate::types::{
+    NaN, NegativeFinite, NonNaN, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+/// A non-NaN value classified into the strictest type accepting it
+///
+/// Matching on it gives a statically typed value in each arm.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// let x = tf64::classify_strictest(-3.0).unwrap();
+///
+/// match x {
+///     Classified::StrictlyPositiveFinite(x) => println!("{} > 0", x.ln()),
+///     Classified::StrictlyNegativeFinite(x) => assert_eq!(-x, 3.0),
+///     Classified::PositiveZero(_) | Classified::NegativeZero(_) => println!("zero"),
+///     Classified::PositiveInfinity(_) | Classified::NegativeInfinity(_) => println!("infinite"),
+/// }
+///
+/// assert_eq!(tf64::classify_strictest(f64::NAN), Err(NaN));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub enum Classified<T = f64> {
+    /// A strictly positive finite value
+    StrictlyPositiveFinite(StrictlyPositiveFinite<T>),
+    /// A strictly negative finite value
+    StrictlyNegativeFinite(StrictlyNegativeFinite<T>),
+    /// `+0.0`
+    PositiveZero(PositiveFinite<T>),
+    /// `-0.0`
+    NegativeZero(NegativeFinite<T>),
+    /// `+inf`
+    PositiveInfinity(StrictlyPositive<T>),
+    /// `-inf`
+    NegativeInfinity(StrictlyNegative<T>),
+}
+
+macro_rules! impl_classified {
+    ($float:ident) => {
+        impl Classified<$float> {
+            /// Classifies the value into the strictest type accepting it
+            ///
+            /// # Errors
+            /// Returns an error if the value is NaN
+            #[inline]
+            #[const_fn("1.83")]
+            pub const fn new(value: $float) -> Result<Self, NaN> {
+                if value.is_nan() {
+                    return Err(NaN);
+                }
+
+                // Safety: each value is only given to a type accepting it
+                let classified = unsafe {
+                    if value == $float::INFINITY {
+                        Self::PositiveInfinity(
+                            StrictlyPositive::<$float>::internal_only_new_unchecked(value),
+                        )
+                    } else if value == $float::NEG_INFINITY {
+                        Self::NegativeInfinity(
+                            StrictlyNegative::<$float>::internal_only_new_unchecked(value),
+                        )
+                    } else if value == 0.0 {
+                        if value.is_sign_positive() {
+                            Self::PositiveZero(
+                                PositiveFinite::<$float>::internal_only_new_unchecked(value),
+                            )
+                        } else {
+                            Self::NegativeZero(
+                                NegativeFinite::<$float>::internal_only_new_unchecked(value),
+                            )
+                        }
+                    } else if value > 0.0 {
+                        Self::StrictlyPositiveFinite(
+                            StrictlyPositiveFinite::<$float>::internal_only_new_unchecked(value),
+                        )
+                    } else {
+                        Self::StrictlyNegativeFinite(
+                            StrictlyNegativeFinite::<$float>::internal_only_new_unchecked(value),
+                        )
+                    }
+                };
+
+                Ok(classified)
+            }
+
+            /// Returns the value as a primitive type
+            #[inline]
+            #[must_use]
+            pub const fn get(&self) -> $float {
+                match self {
+                    Self::StrictlyPositiveFinite(x) => x.get(),
+                    Self::StrictlyNegativeFinite(x) => x.get(),
+                    Self::PositiveZero(x) => x.get(),
+                    Self::NegativeZero(x) => x.get(),
+                    Self::PositiveInfinity(x) => x.get(),
+                    Self::NegativeInfinity(x) => x.get(),
+                }
+            }
+
+            /// Returns the kind of the value
+            #[inline]
+            #[must_use]
+            pub const fn kind(&self) -> FloatKind {
+                match self {
+                    Self::StrictlyPositiveFinite(_) => FloatKind::StrictlyPositiveFinite,
+                    Self::StrictlyNegativeFinite(_) => FloatKind::StrictlyNegativeFinite,
+                    Self::PositiveZero(_) => FloatKind::PositiveFinite,
+                    Self::NegativeZero(_) => FloatKind::NegativeFinite,
+                    Self::PositiveInfinity(_) => FloatKind::StrictlyPositive,
+                    Self::NegativeInfinity(_) => FloatKind::StrictlyNegative,
+                }
+            }
+        }
+
+        impl PartialEq for Classified<$float> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::StrictlyPositiveFinite(a), Self::StrictlyPositiveFinite(b)) => a == b,
+                    (Self::StrictlyNegativeFinite(a), Self::StrictlyNegativeFinite(b)) => a == b,
+                    (Self::PositiveZero(_), Self::PositiveZero(_))
+                    | (Self::NegativeZero(_), Self::NegativeZero(_))
+                    | (Self::PositiveInfinity(_), Self::PositiveInfinity(_))
+                    | (Self::NegativeInfinity(_), Self::NegativeInfinity(_)) => true,
+                    _ => false,
+                }
+            }
+        }
+
+        impl Eq for Classified<$float> {}
+
+        impl TryFrom<$float> for Classified<$float> {
+            type Error = NaN;
+
+            #[inline]
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<NonNaN<$float>> for Classified<$float> {
+            #[inline]
+            fn from(value: NonNaN<$float>) -> Self {
+                match Self::new(value.get()) {
+                    Ok(classified) => classified,
+                    // `NonNaN` is never NaN
+                    Err(NaN) => unreachable!(),
+                }
+            }
+        }
+
+        impl From<Classified<$float>> for NonNaN<$float> {
+            #[inline]
+            fn from(value: Classified<$float>) -> Self {
+                // Safety: none of the variants is NaN
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+
+        impl From<Classified<$float>> for $float {
+            #[inline]
+            fn from(value: Classified<$float>) -> Self {
+                value.get()
+            }
+        }
+    };
+}
+
+impl_classified!(f64);
+impl_classified!(f32);
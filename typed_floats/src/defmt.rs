@@ -0,0 +1,83 @@
+use ::defmt::{Format, Formatter};
+
+use crate::types::{
+    FromStrError, FromStrExactError, InvalidNumber, NaN, Negative, NegativeFinite, NonNaN,
+    NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative,
+    StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite,
+};
+
+impl Format for InvalidNumber {
+    // The messages are interned by `defmt::write!`, so the arms look identical once expanded
+    #[allow(clippy::match_same_arms)]
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            Self::NaN => ::defmt::write!(f, "Number is NaN"),
+            Self::Zero => ::defmt::write!(f, "Number is zero"),
+            Self::Negative => ::defmt::write!(f, "Number is negative"),
+            Self::Positive => ::defmt::write!(f, "Number is positive"),
+            Self::Infinite => ::defmt::write!(f, "Number is infinite"),
+        }
+    }
+}
+
+impl Format for NaN {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "Number is NaN");
+    }
+}
+
+impl Format for FromStrError {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            // `ParseFloatError` doesn't expose its kind, only its message
+            Self::ParseFloatError(e) => ::defmt::write!(f, "{}", ::defmt::Display2Format(e)),
+            Self::InvalidNumber(e) => e.format(f),
+        }
+    }
+}
+
+impl Format for FromStrExactError {
+    #[allow(clippy::match_same_arms)]
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            Self::FromStrError(e) => e.format(f),
+            Self::Overflow => ::defmt::write!(f, "Number is too large to be represented"),
+            Self::Underflow => ::defmt::write!(f, "Number is too small to be represented"),
+        }
+    }
+}
+
+/// The values are printed as `tag(value)`, with the tag made of the initials of the type:
+/// `nn`, `nznn`, `nnf`, `nznnf`, `p`, `n`, `pf`, `nf`, `sp`, `sn`, `spf`, `snf`.
+///
+/// The tag is written as a `str` instead of being interned, so the frames can be checked without the ELF file.
+macro_rules! impl_defmt {
+    ($type:ident, $tag:literal) => {
+        impl Format for $type<f64> {
+            #[inline]
+            fn format(&self, f: Formatter<'_>) {
+                ::defmt::write!(f, "{=str}({=f64})", $tag, self.get());
+            }
+        }
+
+        impl Format for $type<f32> {
+            #[inline]
+            fn format(&self, f: Formatter<'_>) {
+                ::defmt::write!(f, "{=str}({=f32})", $tag, self.get());
+            }
+        }
+    };
+}
+
+impl_defmt!(NonNaN, "nn");
+impl_defmt!(NonZeroNonNaN, "nznn");
+impl_defmt!(NonNaNFinite, "nnf");
+impl_defmt!(NonZeroNonNaNFinite, "nznnf");
+impl_defmt!(Positive, "p");
+impl_defmt!(Negative, "n");
+impl_defmt!(PositiveFinite, "pf");
+impl_defmt!(NegativeFinite, "nf");
+impl_defmt!(StrictlyPositive, "sp");
+impl_defmt!(StrictlyNegative, "sn");
+impl_defmt!(StrictlyPositiveFinite, "spf");
+impl_defmt!(StrictlyNegativeFinite, "snf");
@@ -0,0 +1,61 @@
+/// One value out of `EDGE_DENOMINATOR` is picked from the edge values used by the tests.
+pub const EDGE_DENOMINATOR: u8 = 4;
+
+macro_rules! impl_edge_values {
+    ($name:ident, $float:ident, $module:ident) => {
+        /// The edge values used by the tests that are accepted by `T`.
+        pub fn $name<T: TryFrom<$float>>() -> impl Iterator<Item = T> {
+            crate::$module::get_test_values()
+                .into_iter()
+                .filter_map(|x| T::try_from(x).ok())
+        }
+    };
+}
+
+impl_edge_values!(f64_edge_values, f64, tf64);
+impl_edge_values!(f32_edge_values, f32, tf32);
+
+macro_rules! impl_from_bits {
+    ($name:ident, $float:ident, $bits:ident) => {
+        /// Maps any bit pattern onto a value accepted by the kind, without rejection:
+        /// - `NaN` (and infinities when rejected) lose their highest exponent bit and become finite;
+        /// - the sign bit is forced if only one sign is accepted;
+        /// - zero becomes the smallest subnormal if rejected.
+        pub fn $name(
+            bits: $bits,
+            accept_inf: bool,
+            accept_zero: bool,
+            accept_positive: bool,
+            accept_negative: bool,
+        ) -> $float {
+            const SIGN: $bits = 1 << ($bits::BITS - 1);
+            const MANTISSA: $bits = (1 << ($float::MANTISSA_DIGITS - 1)) - 1;
+            const EXPONENT: $bits = !(SIGN | MANTISSA);
+            const TOP_EXPONENT_BIT: $bits = SIGN >> 1;
+
+            let mut bits = bits;
+
+            let is_nan_or_inf = bits & EXPONENT == EXPONENT;
+            let is_nan = is_nan_or_inf && bits & MANTISSA != 0;
+
+            if is_nan || (is_nan_or_inf && !accept_inf) {
+                bits &= !TOP_EXPONENT_BIT;
+            }
+
+            if !accept_negative {
+                bits &= !SIGN;
+            } else if !accept_positive {
+                bits |= SIGN;
+            }
+
+            if !accept_zero && bits & !SIGN == 0 {
+                bits |= 1;
+            }
+
+            $float::from_bits(bits)
+        }
+    };
+}
+
+impl_from_bits!(f64_from_bits, f64, u64);
+impl_from_bits!(f32_from_bits, f32, u32);
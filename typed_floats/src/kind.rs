@@ -0,0 +1,157 @@
+use const_fn::const_fn;
+
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+typed_floats_macros::generate_float_kind!();
+
+impl FloatKind {
+    /// Returns the kind of the typed float `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// assert_eq!(FloatKind::of::<Positive<f32>>(), FloatKind::Positive);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn of<T: HasFloatKind>() -> Self {
+        T::KIND
+    }
+
+    /// Returns true if the value is accepted by the kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// assert!(FloatKind::Positive.accepts(0.0));
+    /// assert!(!FloatKind::Positive.accepts(-0.0));
+    /// assert!(!FloatKind::NonNaN.accepts(f64::NAN));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[const_fn("1.83")]
+    pub const fn accepts(self, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+
+        if value.is_infinite() && !self.accept_infinity() {
+            return false;
+        }
+
+        if value == 0.0 && !self.accept_zero() {
+            return false;
+        }
+
+        if value.is_sign_negative() {
+            self.accept_negative()
+        } else {
+            self.accept_positive()
+        }
+    }
+
+    /// Returns true if all the values accepted by `self` are accepted by `into`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// assert!(FloatKind::StrictlyPositive.can_fit_into(FloatKind::NonZeroNonNaN));
+    /// assert!(!FloatKind::Positive.can_fit_into(FloatKind::StrictlyPositive));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn can_fit_into(self, into: Self) -> bool {
+        (!self.accept_infinity() || into.accept_infinity())
+            && (!self.accept_zero() || into.accept_zero())
+            && (!self.accept_positive() || into.accept_positive())
+            && (!self.accept_negative() || into.accept_negative())
+    }
+
+    /// Returns the smallest kind accepting all the values accepted by `self` or `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// assert_eq!(
+    ///     FloatKind::StrictlyPositiveFinite.join(FloatKind::NegativeFinite),
+    ///     FloatKind::NonNaNFinite
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn join(self, other: Self) -> Self {
+        match Self::from_accept(
+            self.accept_infinity() || other.accept_infinity(),
+            self.accept_zero() || other.accept_zero(),
+            self.accept_positive() || other.accept_positive(),
+            self.accept_negative() || other.accept_negative(),
+        ) {
+            Some(kind) => kind,
+            // Both kinds accept at least one sign, so a kind accepts the union
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the largest kind accepting only the values accepted by both `self` and `other`,
+    /// or `None` if they have no values in common
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// assert_eq!(
+    ///     FloatKind::Positive.meet(FloatKind::NonZeroNonNaNFinite),
+    ///     Some(FloatKind::StrictlyPositiveFinite)
+    /// );
+    /// assert_eq!(FloatKind::Positive.meet(FloatKind::Negative), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn meet(self, other: Self) -> Option<Self> {
+        Self::from_accept(
+            self.accept_infinity() && other.accept_infinity(),
+            self.accept_zero() && other.accept_zero(),
+            self.accept_positive() && other.accept_positive(),
+            self.accept_negative() && other.accept_negative(),
+        )
+    }
+}
+
+/// A typed float whose kind is known at compile time
+pub trait HasFloatKind {
+    /// The kind of the type
+    const KIND: FloatKind;
+}
+
+macro_rules! impl_has_float_kind {
+    ($type:ident) => {
+        impl HasFloatKind for $type<f64> {
+            const KIND: FloatKind = FloatKind::$type;
+        }
+
+        impl HasFloatKind for $type<f32> {
+            const KIND: FloatKind = FloatKind::$type;
+        }
+    };
+}
+
+impl_has_float_kind!(NonNaN);
+impl_has_float_kind!(NonZeroNonNaN);
+impl_has_float_kind!(NonNaNFinite);
+impl_has_float_kind!(NonZeroNonNaNFinite);
+impl_has_float_kind!(Positive);
+impl_has_float_kind!(Negative);
+impl_has_float_kind!(PositiveFinite);
+impl_has_float_kind!(NegativeFinite);
+impl_has_float_kind!(StrictlyPositive);
+impl_has_float_kind!(StrictlyNegative);
+impl_has_float_kind!(StrictlyPositiveFinite);
+impl_has_float_kind!(StrictlyNegativeFinite);
@@ -102,21 +102,138 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-// `format!` is used during the tests even in `no_std` environments
-#[cfg(all(test, not(feature = "std")))]
-#[macro_use]
+// `format!` is used during the tests even in `no_std` environments,
+// and the collections by the `proptest` strategies, the `quickcheck` generators and `borsh`
+#[cfg(any(
+    all(test, not(feature = "std")),
+    feature = "proptest",
+    feature = "quickcheck",
+    feature = "borsh"
+))]
+#[cfg_attr(all(test, not(feature = "std")), macro_use)]
 extern crate alloc;
 
+mod bits;
+mod classify;
+mod kind;
 mod macros;
+mod refine;
 mod traits;
 mod types;
 
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "serde")]
+pub mod serde_f32;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv::ArchivedTypedFloat;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+#[cfg(feature = "ufmt")]
+mod ufmt;
+
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+mod generators;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+
+#[cfg(feature = "rand")]
+mod rand;
+
+#[cfg(feature = "rand")]
+pub use crate::rand::{BitPatterns, UniformTypedFloat};
+
+#[cfg(feature = "rand_distr")]
+mod rand_distr;
+
+#[cfg(feature = "rand_distr")]
+pub use crate::rand_distr::{ExpFromTyped, GammaFromTyped, NormalFromTyped};
+
+pub use classify::*;
+pub use kind::*;
+pub use refine::*;
 pub use traits::*;
 pub use types::*;
 
+/// Macro to create a constant of the strictest type accepting a literal.
+///
+/// The literal is checked at compile time:
+/// - A strictly positive literal gives a [`StrictlyPositiveFinite`]
+/// - A strictly negative literal gives a [`StrictlyNegativeFinite`]
+/// - `0.0` gives a [`PositiveFinite`] and `-0.0` a [`NegativeFinite`]
+/// - `INFINITY` gives a [`StrictlyPositive`] and `NEG_INFINITY` a [`StrictlyNegative`]
+///
+/// The values are `f64` by default, `f32` can be selected with `tf!(f32, 1.0)` or a suffix.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// const TWO: StrictlyPositiveFinite = tf!(2.0);
+/// const MINUS_TWO: StrictlyNegativeFinite = tf!(-2);
+/// const ZERO: PositiveFinite = tf!(0.0);
+/// const MINUS_ZERO: NegativeFinite = tf!(-0.0);
+/// const INF: StrictlyPositive = tf!(INFINITY);
+/// const NEG_INF: StrictlyNegative<f32> = tf!(f32::NEG_INFINITY);
+/// const ONE: StrictlyPositiveFinite<f32> = tf!(f32, 1.0);
+/// const HALF: StrictlyPositiveFinite<f32> = tf!(0.5f32);
+///
+/// let x: NonNaN = tf!(1.5).into();
+/// ```
+///
+/// Those examples will not compile:
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// // NaN is not valid
+/// let x = tf!(f64::NAN);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// // The literal overflows to infinity in f32
+/// let x = tf!(f32, 1e39);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// // The literal underflows to zero in f64
+/// let x = tf!(1e-400);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// // The precision doesn't match the suffix of the literal
+/// let x = tf!(f64, 1.0f32);
+/// ```
+#[macro_export]
+macro_rules! tf {
+    ($($input:tt)*) => {
+        $crate::__tf!($crate; $($input)*)
+    };
+}
+
+// The procedural macro can't know the path of this crate, `tf!` passes `$crate` to it
+#[doc(hidden)]
+pub use typed_floats_macros::tf as __tf;
+
 typed_floats_macros::generate_docs!(
     pub mod conversions_rules {}
 );
@@ -197,6 +314,26 @@ pub mod tf64 {
         x == 0.0 && x.is_sign_negative()
     }
 
+    /// Classifies the number into the strictest type accepting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    ///
+    /// assert_eq!(tf64::classify_strictest(3.0).unwrap().kind(), FloatKind::StrictlyPositiveFinite);
+    /// assert_eq!(tf64::classify_strictest(-0.0).unwrap().kind(), FloatKind::NegativeFinite);
+    /// assert_eq!(tf64::classify_strictest(f64::NAN), Err(NaN));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the number is NaN
+    #[inline]
+    #[const_fn("1.83")]
+    pub const fn classify_strictest(x: f64) -> Result<crate::Classified<f64>, crate::NaN> {
+        crate::Classified::<f64>::new(x)
+    }
+
     const fn from_bits(bits: u64) -> f64 {
         // SAFETY: it is a plain old datatype so we can always transmute from it.
         // `f64::from_bits` is not const for `1.70` MSRV
@@ -544,6 +681,26 @@ pub mod tf32 {
         x == 0.0 && x.is_sign_negative()
     }
 
+    /// Classifies the number into the strictest type accepting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    ///
+    /// assert_eq!(tf32::classify_strictest(3.0).unwrap().kind(), FloatKind::StrictlyPositiveFinite);
+    /// assert_eq!(tf32::classify_strictest(-0.0).unwrap().kind(), FloatKind::NegativeFinite);
+    /// assert_eq!(tf32::classify_strictest(f32::NAN), Err(NaN));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the number is NaN
+    #[inline]
+    #[const_fn("1.83")]
+    pub const fn classify_strictest(x: f32) -> Result<crate::Classified<f32>, crate::NaN> {
+        crate::Classified::<f32>::new(x)
+    }
+
     crate::generate_const!(
         INFINITY,
         StrictlyPositive,
@@ -813,6 +970,13 @@ mod tests {
         }
     }
 
+    // The path of the crate is `crate` here, not `::typed_floats`
+    #[test]
+    fn test_tf() {
+        const TWO: StrictlyPositiveFinite = tf!(2.0);
+        assert_eq!(TWO, 2.0);
+    }
+
     #[test]
     fn test_f32() {
         let mut values = tf32::get_test_values().to_vec();
@@ -0,0 +1,588 @@
+//! [`proptest`](https://docs.rs/proptest) strategies generating only valid values.
+//!
+//! All the types implement [`Arbitrary`], so `any::<Positive>()` generates
+//! any valid [`Positive`]. The functions of this module restrict the generated values to a range:
+//!
+//! ```
+//! use ::proptest::prelude::*;
+//! use typed_floats::*;
+//!
+//! proptest!(|(x in any::<Positive>())| {
+//!     prop_assert!(x >= 0.0);
+//! });
+//!
+//! proptest!(|(x in typed_floats::proptest::strictly_positive_finite(1.0f64..=1e300).unwrap())| {
+//!     prop_assert!(x.recip() <= 1.0);
+//! });
+//! ```
+//!
+//! Values are drawn uniformly among the representable floats (so subnormals and huge values
+//! are as likely as `1.0`), and the edge values (`±0.0`, subnormals, `MIN_POSITIVE`, `MAX`, `±∞`
+//! when accepted by the kind) and the bounds of the range are picked with a configurable weight,
+//! see [`TypedFloatStrategy::with_edge_weight`].
+//!
+//! When shrinking, the values move toward `0.0`, then `±1.0`, then `±MIN_POSITIVE`,
+//! without ever leaving the kind nor the range.
+//!
+//! The functions return an [`InvalidStrategy`] error instead of panicking if a bound is `NaN`
+//! or if no valid value is in the range.
+
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::strategy::{NewTree, Strategy, ValueTree};
+use ::proptest::test_runner::TestRunner;
+
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+/// Probability to pick one of the edge values when none is specified.
+const DEFAULT_EDGE_WEIGHT: f64 = 0.25;
+
+mod sealed {
+    use super::Vec;
+
+    /// A primitive float (`f32` or `f64`).
+    ///
+    /// Its values are mapped onto "keys" whose unsigned order is the order of the floats,
+    /// with `-0.0` and `+0.0` being two consecutive keys.
+    pub trait Float: Copy + PartialOrd + core::fmt::Debug {
+        const ZERO: Self;
+        const NEG_ZERO: Self;
+        const ONE: Self;
+        const NEG_ONE: Self;
+        const MIN_POSITIVE: Self;
+        const NEG_MIN_POSITIVE: Self;
+        const MAX: Self;
+        const MIN: Self;
+        const INFINITY: Self;
+        const NEG_INFINITY: Self;
+
+        fn to_key(self) -> u64;
+        fn from_key(key: u64) -> Self;
+        fn is_nan(self) -> bool;
+        fn test_values() -> Vec<Self>;
+    }
+
+    /// One of the typed floats.
+    pub trait TypedFloatImpl: Copy + core::fmt::Debug {
+        type Float: Float;
+
+        const ACCEPT_INF: bool;
+        const ACCEPT_ZERO: bool;
+        const ACCEPT_POSITIVE: bool;
+        const ACCEPT_NEGATIVE: bool;
+
+        fn is_valid(value: Self::Float) -> bool;
+        fn get_float(self) -> Self::Float;
+
+        /// # Safety
+        /// The value must be valid for the type.
+        unsafe fn from_valid(value: Self::Float) -> Self;
+    }
+}
+
+use sealed::{Float, TypedFloatImpl};
+
+/// The typed floats whose primitive is `F`, implemented for all the types of this crate.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait TypedFloat<F>: TypedFloatImpl<Float = F> {}
+
+/// An error that can occur when creating a strategy
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidStrategy {
+    /// A bound of the range is `NaN`
+    NaNBound,
+    /// The range does not contain any valid value
+    EmptyRange,
+    /// The edge weight is not in `[0.0, 1.0]`
+    InvalidWeight,
+}
+
+impl core::fmt::Display for InvalidStrategy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NaNBound => write!(f, "A bound of the range is NaN"),
+            Self::EmptyRange => write!(f, "The range does not contain any valid value"),
+            Self::InvalidWeight => write!(f, "The edge weight is not in [0.0, 1.0]"),
+        }
+    }
+}
+
+#[rustversion::since(1.81)]
+impl core::error::Error for InvalidStrategy {}
+
+#[rustversion::before(1.81)]
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidStrategy {}
+
+macro_rules! impl_float {
+    ($float:ident, $bits:ident, $module:ident) => {
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const NEG_ZERO: Self = -0.0;
+            const ONE: Self = 1.0;
+            const NEG_ONE: Self = -1.0;
+            const MIN_POSITIVE: Self = $float::MIN_POSITIVE;
+            const NEG_MIN_POSITIVE: Self = -$float::MIN_POSITIVE;
+            const MAX: Self = $float::MAX;
+            const MIN: Self = $float::MIN;
+            const INFINITY: Self = $float::INFINITY;
+            const NEG_INFINITY: Self = $float::NEG_INFINITY;
+
+            #[inline]
+            fn to_key(self) -> u64 {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                let bits = self.to_bits();
+
+                let key = if bits & SIGN == 0 { bits | SIGN } else { !bits };
+
+                u64::from(key)
+            }
+
+            #[inline]
+            fn from_key(key: u64) -> Self {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                // The keys are always created from a value of the same type
+                #[allow(clippy::cast_possible_truncation)]
+                let key = key as $bits;
+
+                let bits = if key & SIGN == 0 { !key } else { key & !SIGN };
+
+                Self::from_bits(bits)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                self.is_nan()
+            }
+
+            fn test_values() -> Vec<Self> {
+                crate::$module::get_test_values().to_vec()
+            }
+        }
+    };
+}
+
+impl_float!(f64, u64, tf64);
+impl_float!(f32, u32, tf32);
+
+/// The keys of the valid values: `[lo, hi]` without `[hole_lo, hole_hi]` (the zeros)
+#[derive(Clone, Copy, Debug)]
+struct Domain {
+    lo: u64,
+    hi: u64,
+    hole: Option<(u64, u64)>,
+}
+
+impl Domain {
+    /// All the valid values of `T`
+    fn of<T: TypedFloatImpl>() -> Self {
+        let zero = T::Float::ZERO.to_key();
+        let neg_zero = T::Float::NEG_ZERO.to_key();
+
+        let kind_lo = if T::ACCEPT_NEGATIVE {
+            if T::ACCEPT_INF {
+                T::Float::NEG_INFINITY.to_key()
+            } else {
+                T::Float::MIN.to_key()
+            }
+        } else if T::ACCEPT_ZERO {
+            zero
+        } else {
+            zero + 1
+        };
+
+        let kind_hi = if T::ACCEPT_POSITIVE {
+            if T::ACCEPT_INF {
+                T::Float::INFINITY.to_key()
+            } else {
+                T::Float::MAX.to_key()
+            }
+        } else if T::ACCEPT_ZERO {
+            neg_zero
+        } else {
+            neg_zero - 1
+        };
+
+        Self::new::<T>(kind_lo, kind_hi)
+    }
+
+    /// The valid values of `T` within `range`
+    fn restrict<T: TypedFloatImpl>(
+        self,
+        range: &impl RangeBounds<T::Float>,
+    ) -> Result<Self, InvalidStrategy> {
+        let zero = T::Float::ZERO.to_key();
+        let neg_zero = T::Float::NEG_ZERO.to_key();
+
+        let not_nan = |x: &T::Float| {
+            if x.is_nan() {
+                Err(InvalidStrategy::NaNBound)
+            } else {
+                Ok(*x)
+            }
+        };
+
+        // `-0.0 == 0.0` so both are inside (or outside) the range
+        // The excluded bounds can't overflow: they would have to be `NaN`
+        let range_lo = match range.start_bound() {
+            Bound::Included(x) if not_nan(x)? == T::Float::ZERO => neg_zero,
+            Bound::Included(x) => x.to_key(),
+            Bound::Excluded(x) if not_nan(x)? == T::Float::ZERO => zero + 1,
+            Bound::Excluded(x) => x.to_key().saturating_add(1),
+            Bound::Unbounded => self.lo,
+        };
+
+        let range_hi = match range.end_bound() {
+            Bound::Included(x) if not_nan(x)? == T::Float::ZERO => zero,
+            Bound::Included(x) => x.to_key(),
+            Bound::Excluded(x) if not_nan(x)? == T::Float::ZERO => neg_zero - 1,
+            Bound::Excluded(x) => x.to_key().saturating_sub(1),
+            Bound::Unbounded => self.hi,
+        };
+
+        let lo = self.lo.max(range_lo);
+        let hi = self.hi.min(range_hi);
+
+        if lo > hi {
+            return Err(InvalidStrategy::EmptyRange);
+        }
+
+        let domain = Self::new::<T>(lo, hi);
+
+        if domain.last_index().is_none() {
+            return Err(InvalidStrategy::EmptyRange);
+        }
+
+        Ok(domain)
+    }
+
+    /// The valid values of `T` within `[lo, hi]`, with `lo <= hi`
+    fn new<T: TypedFloatImpl>(lo: u64, hi: u64) -> Self {
+        let zero = T::Float::ZERO.to_key();
+        let neg_zero = T::Float::NEG_ZERO.to_key();
+
+        let hole = if T::ACCEPT_ZERO {
+            None
+        } else {
+            let hole_lo = lo.max(neg_zero);
+            let hole_hi = hi.min(zero);
+
+            if hole_lo <= hole_hi {
+                Some((hole_lo, hole_hi))
+            } else {
+                None
+            }
+        };
+
+        Self { lo, hi, hole }
+    }
+
+    /// Index of the last valid key, `None` if there is none
+    const fn last_index(&self) -> Option<u64> {
+        let len = self.hi - self.lo + 1;
+
+        match self.hole {
+            Some((hole_lo, hole_hi)) => (len - (hole_hi - hole_lo + 1)).checked_sub(1),
+            None => len.checked_sub(1),
+        }
+    }
+
+    /// The `index`-th valid key
+    const fn nth(&self, index: u64) -> u64 {
+        let key = self.lo + index;
+
+        match self.hole {
+            Some((hole_lo, hole_hi)) if key >= hole_lo => key + (hole_hi - hole_lo + 1),
+            _ => key,
+        }
+    }
+
+    const fn contains(&self, key: u64) -> bool {
+        let is_in_hole = match self.hole {
+            Some((hole_lo, hole_hi)) => key >= hole_lo && key <= hole_hi,
+            None => false,
+        };
+
+        key >= self.lo && key <= self.hi && !is_in_hole
+    }
+
+    /// The simplest valid key to shrink `key` toward, without crossing the zeros if invalid
+    fn target<T: TypedFloatImpl>(&self, key: u64) -> u64 {
+        let zero = T::Float::ZERO.to_key();
+        let neg_zero = T::Float::NEG_ZERO.to_key();
+        let is_positive = key >= zero;
+
+        let candidates = if is_positive {
+            [T::Float::ONE, T::Float::MIN_POSITIVE]
+        } else {
+            [T::Float::NEG_ONE, T::Float::NEG_MIN_POSITIVE]
+        };
+
+        [T::Float::ZERO, T::Float::NEG_ZERO]
+            .into_iter()
+            .chain(candidates)
+            .map(Float::to_key)
+            .find(|&candidate| self.contains(candidate))
+            .unwrap_or_else(|| {
+                if is_positive {
+                    self.lo.max(zero + 1)
+                } else {
+                    self.hi.min(neg_zero - 1)
+                }
+            })
+    }
+}
+
+/// Strategy generating the valid values of `T` within a range.
+///
+/// Created by [`Arbitrary::arbitrary`] or the functions of this module.
+#[derive(Clone, Debug)]
+#[must_use = "strategies do nothing unless used"]
+pub struct TypedFloatStrategy<T> {
+    domain: Domain,
+    edges: Vec<T>,
+    edge_weight: f64,
+}
+
+impl<T: TypedFloatImpl> TypedFloatStrategy<T> {
+    fn new(domain: Domain) -> Self {
+        let edges = T::Float::test_values()
+            .into_iter()
+            .chain([T::Float::from_key(domain.lo), T::Float::from_key(domain.hi)])
+            .filter(|&x| !x.is_nan() && T::is_valid(x) && domain.contains(x.to_key()))
+            .map(|x| {
+                // # Safety
+                // This is safe because only the valid values have been kept.
+                unsafe { T::from_valid(x) }
+            })
+            .collect();
+
+        Self {
+            domain,
+            edges,
+            edge_weight: DEFAULT_EDGE_WEIGHT,
+        }
+    }
+
+    /// Sets the probability to generate one of the edge values (`±0.0`, subnormals,
+    /// `MIN_POSITIVE`, `MAX`, `±∞`, the bounds of the range...) instead of
+    /// any value of the range. Defaults to `0.25`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::proptest::positive;
+    ///
+    /// let never_edges = positive::<f64>(..).unwrap().with_edge_weight(0.0);
+    /// let always_edges = positive::<f64>(..).unwrap().with_edge_weight(1.0);
+    ///
+    /// assert!(never_edges.is_ok());
+    /// assert!(always_edges.is_ok());
+    /// assert!(positive::<f64>(..).unwrap().with_edge_weight(2.0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`InvalidStrategy::InvalidWeight`] if `weight` is not in `[0.0, 1.0]`.
+    pub fn with_edge_weight(mut self, weight: f64) -> Result<Self, InvalidStrategy> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(InvalidStrategy::InvalidWeight);
+        }
+
+        self.edge_weight = weight;
+        Ok(self)
+    }
+}
+
+impl<T: TypedFloatImpl> Strategy for TypedFloatStrategy<T> {
+    type Tree = TypedFloatValueTree<T>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let pick_edge = !self.edges.is_empty()
+            && ::proptest::bool::weighted(self.edge_weight)
+                .new_tree(runner)?
+                .current();
+
+        if pick_edge {
+            let index = (0..self.edges.len()).new_tree(runner)?.current();
+
+            if let Some(edge) = self.edges.get(index) {
+                let key = edge.get_float().to_key();
+
+                return Ok(TypedFloatValueTree::new(key, &self.domain));
+            }
+        }
+
+        let last_index = self.domain.last_index().unwrap_or_default();
+        let index = (0..=last_index).new_tree(runner)?.current();
+        let key = self.domain.nth(index);
+
+        Ok(TypedFloatValueTree::new(key, &self.domain))
+    }
+}
+
+/// Value tree shrinking a typed float toward a simple value, without leaving its kind.
+///
+/// The shrinking is a binary search on the distance (in number of representable floats)
+/// to that simple value.
+#[derive(Clone, Copy, Debug)]
+pub struct TypedFloatValueTree<T> {
+    target: u64,
+    is_above_target: bool,
+    lo: u64,
+    curr: u64,
+    hi: u64,
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<T: TypedFloatImpl> TypedFloatValueTree<T> {
+    fn new(key: u64, domain: &Domain) -> Self {
+        let target = domain.target::<T>(key);
+        let distance = key.abs_diff(target);
+
+        Self {
+            target,
+            is_above_target: key >= target,
+            lo: 0,
+            curr: distance,
+            hi: distance,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: TypedFloatImpl> ValueTree for TypedFloatValueTree<T> {
+    type Value = T;
+
+    fn current(&self) -> T {
+        let key = if self.is_above_target {
+            self.target + self.curr
+        } else {
+            self.target - self.curr
+        };
+
+        // # Safety
+        // All the keys between the target and the generated value are valid.
+        unsafe { T::from_valid(T::Float::from_key(key)) }
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.curr == self.lo {
+            return false;
+        }
+
+        self.hi = self.curr;
+        self.curr = self.lo + (self.hi - self.lo) / 2;
+
+        true
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.curr >= self.hi {
+            return false;
+        }
+
+        self.lo = self.curr + 1;
+        self.curr = self.lo + (self.hi - self.lo) / 2;
+
+        true
+    }
+}
+
+macro_rules! impl_proptest {
+    ($fn:ident, $type:ident) => {
+        impl_proptest!(impl $type, f64);
+        impl_proptest!(impl $type, f32);
+
+        #[doc = concat!("Strategy generating the [`", stringify!($type), "`] values within `range`.")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use typed_floats::proptest::", stringify!($fn), ";")]
+        ///
+        #[doc = concat!("let all = ", stringify!($fn), "::<f64>(..);")]
+        #[doc = concat!("let some = ", stringify!($fn), "(-1.0f64..=1.0);")]
+        #[doc = concat!("let some_f32 = ", stringify!($fn), "(-1.0f32..=1.0);")]
+        ///
+        /// assert!(all.is_ok());
+        /// assert!(some.is_ok());
+        /// assert!(some_f32.is_ok());
+        #[doc = concat!("assert!(", stringify!($fn), "(f64::NAN..).is_err());")]
+        /// ```
+        ///
+        /// # Errors
+        /// Returns [`InvalidStrategy::NaNBound`] if a bound is `NaN`, or [`InvalidStrategy::EmptyRange`]
+        /// if no valid value is in `range`.
+        pub fn $fn<F>(
+            range: impl RangeBounds<F>,
+        ) -> Result<TypedFloatStrategy<$type<F>>, InvalidStrategy>
+        where
+            $type<F>: TypedFloat<F>,
+        {
+            let domain = Domain::of::<$type<F>>().restrict::<$type<F>>(&range)?;
+
+            Ok(TypedFloatStrategy::new(domain))
+        }
+    };
+    (impl $type:ident, $float:ident) => {
+        impl TypedFloat<$float> for $type<$float> {}
+
+        impl TypedFloatImpl for $type<$float> {
+            type Float = $float;
+
+            const ACCEPT_INF: bool = crate::$type::accept_infinity();
+            const ACCEPT_ZERO: bool = crate::$type::accept_zero();
+            const ACCEPT_POSITIVE: bool = crate::$type::accept_positive();
+            const ACCEPT_NEGATIVE: bool = crate::$type::accept_negative();
+
+            #[inline]
+            fn is_valid(value: $float) -> bool {
+                Self::new(value).is_ok()
+            }
+
+            #[inline]
+            fn get_float(self) -> $float {
+                self.get()
+            }
+
+            #[inline]
+            unsafe fn from_valid(value: $float) -> Self {
+                // # Safety
+                // The caller ensures that the value is valid.
+                unsafe { Self::new_unchecked(value) }
+            }
+        }
+
+        impl Arbitrary for $type<$float> {
+            type Parameters = ();
+            type Strategy = TypedFloatStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                TypedFloatStrategy::new(Domain::of::<Self>())
+            }
+        }
+    };
+}
+
+impl_proptest!(non_nan, NonNaN);
+impl_proptest!(non_zero_non_nan, NonZeroNonNaN);
+impl_proptest!(non_nan_finite, NonNaNFinite);
+impl_proptest!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_proptest!(positive, Positive);
+impl_proptest!(negative, Negative);
+impl_proptest!(positive_finite, PositiveFinite);
+impl_proptest!(negative_finite, NegativeFinite);
+impl_proptest!(strictly_positive, StrictlyPositive);
+impl_proptest!(strictly_negative, StrictlyNegative);
+impl_proptest!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_proptest!(strictly_negative_finite, StrictlyNegativeFinite);
@@ -0,0 +1,94 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::generators::{
+    f32_edge_values, f32_from_bits, f64_edge_values, f64_from_bits, EDGE_DENOMINATOR,
+};
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+macro_rules! impl_shrink {
+    ($name:ident, $float:ident) => {
+        /// Values simpler than `x`, all of the same sign, the simplest first:
+        /// `±0.0` if accepted (`±1.0` otherwise), then the values halfway toward `x`.
+        /// Infinities are first shrunk to `±MAX`.
+        fn $name(x: $float, accept_zero: bool) -> impl Iterator<Item = $float> {
+            let sign: $float = if x.is_sign_negative() { -1.0 } else { 1.0 };
+            let target = if accept_zero { 0.0 * sign } else { sign };
+            let start = if x.is_infinite() {
+                $float::MAX * sign
+            } else {
+                x
+            };
+
+            let halves = core::iter::successors(Some((start - target) / 2.0), |&d| Some(d / 2.0))
+                .map(move |d| start - d)
+                .take_while(move |&y| y.to_bits() != start.to_bits());
+
+            core::iter::once(target)
+                .chain(x.is_infinite().then_some(start))
+                .chain(halves)
+                .filter(move |&y| y.to_bits() != x.to_bits())
+        }
+    };
+}
+
+impl_shrink!(f64_shrink, f64);
+impl_shrink!(f32_shrink, f32);
+
+macro_rules! impl_quickcheck {
+    ($type:ident) => {
+        impl_quickcheck!($type, f64, u64, f64_edge_values, f64_from_bits, f64_shrink);
+        impl_quickcheck!($type, f32, u32, f32_edge_values, f32_from_bits, f32_shrink);
+    };
+    ($type:ident, $float:ident, $bits:ident, $edge_values:ident, $from_bits:ident, $shrink:ident) => {
+        impl Arbitrary for $type<$float> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                if u8::arbitrary(g) % EDGE_DENOMINATOR == 0 {
+                    let edges = $edge_values::<Self>().collect::<Vec<Self>>();
+
+                    if let Some(&value) = g.choose(&edges) {
+                        return value;
+                    }
+                }
+
+                let value = $from_bits(
+                    $bits::arbitrary(g),
+                    crate::$type::accept_infinity(),
+                    crate::$type::accept_zero(),
+                    crate::$type::accept_positive(),
+                    crate::$type::accept_negative(),
+                );
+
+                // # Safety
+                // This is safe because the bits have been mapped onto a valid value.
+                unsafe { Self::new_unchecked(value) }
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let values = $shrink(self.get(), crate::$type::accept_zero());
+
+                // Only the values of the same kind are kept
+                Box::new(values.filter_map(|x| Self::new(x).ok()))
+            }
+        }
+    };
+}
+
+impl_quickcheck!(NonNaN);
+impl_quickcheck!(NonZeroNonNaN);
+impl_quickcheck!(NonNaNFinite);
+impl_quickcheck!(NonZeroNonNaNFinite);
+impl_quickcheck!(Positive);
+impl_quickcheck!(Negative);
+impl_quickcheck!(PositiveFinite);
+impl_quickcheck!(NegativeFinite);
+impl_quickcheck!(StrictlyPositive);
+impl_quickcheck!(StrictlyNegative);
+impl_quickcheck!(StrictlyPositiveFinite);
+impl_quickcheck!(StrictlyNegativeFinite);
@@ -0,0 +1,165 @@
+use core::marker::PhantomData;
+
+use ::rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
+use ::rand::distr::{Distribution, OpenClosed01, StandardUniform};
+use ::rand::Rng;
+
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+/// Distribution sampling uniformly among all the bit patterns accepted by a type,
+/// from the subnormals to the infinities (if accepted).
+///
+/// This is not a uniform distribution over the real numbers: there are as many values in `[1.0, 2.0)` as in `[2.0, 4.0)`.
+/// It is meant to test code against all the values a type can hold.
+///
+/// # Examples
+///
+/// ```
+/// use rand::distr::Distribution;
+/// use rand::rngs::SmallRng;
+/// use rand::SeedableRng;
+/// use typed_floats::*;
+///
+/// let mut rng = SmallRng::seed_from_u64(0);
+///
+/// let x: StrictlyNegative = BitPatterns.sample(&mut rng);
+/// assert!(x < 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BitPatterns;
+
+/// Sampler of the values between two bounds of the same type, created by [`SampleUniform`].
+///
+/// `rng.random_range(a..b)` returns the type of `a` and `b`, not the strictest type
+/// accepting all the values between them: `NonNaN` bounds `1.0..2.0` give a `NonNaN`,
+/// not a `StrictlyPositiveFinite`. The result can be narrowed with `try_into`.
+/// The bounds must be finite, as with [`f64`].
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::SmallRng;
+/// use rand::{RngExt, SeedableRng};
+/// use typed_floats::*;
+///
+/// let mut rng = SmallRng::seed_from_u64(0);
+///
+/// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+/// let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+///
+/// let x: StrictlyPositiveFinite = rng.random_range(a..b);
+/// assert!(x >= 1.0 && x < 2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformTypedFloat<T, F> {
+    inner: UniformFloat<F>,
+    kind: PhantomData<T>,
+}
+
+macro_rules! impl_rand {
+    ($type:ident, $standard:ident $(, $neg:tt)?) => {
+        impl_rand!($type, $standard, [$($neg)?], f64, u64, next_u64);
+        impl_rand!($type, $standard, [$($neg)?], f32, u32, next_u32);
+    };
+    ($type:ident, $standard:ident, [$($neg:tt)?], $float:ident, $bits:ident, $next:ident) => {
+        impl Distribution<$type<$float>> for StandardUniform {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type<$float> {
+                let x: $float = $standard.sample(rng);
+
+                // # Safety
+                // `$standard` samples in `[0, 1)` or `(0, 1]`, which is valid once negated if needed
+                unsafe { $type::<$float>::new_unchecked($($neg)? x) }
+            }
+        }
+
+        impl Distribution<$type<$float>> for BitPatterns {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type<$float> {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                loop {
+                    let mut bits = rng.$next();
+
+                    // Forcing the sign halves the number of rejections
+                    if !crate::$type::accept_negative() {
+                        bits &= !SIGN;
+                    } else if !crate::$type::accept_positive() {
+                        bits |= SIGN;
+                    }
+
+                    if let Ok(x) = $type::<$float>::new($float::from_bits(bits)) {
+                        return x;
+                    }
+                }
+            }
+        }
+
+        impl SampleUniform for $type<$float> {
+            type Sampler = UniformTypedFloat<Self, $float>;
+        }
+
+        impl UniformSampler for UniformTypedFloat<$type<$float>, $float> {
+            type X = $type<$float>;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Ok(Self {
+                    inner: UniformFloat::<$float>::new(low.borrow().get(), high.borrow().get())?,
+                    kind: PhantomData,
+                })
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Ok(Self {
+                    inner: UniformFloat::<$float>::new_inclusive(
+                        low.borrow().get(),
+                        high.borrow().get(),
+                    )?,
+                    kind: PhantomData,
+                })
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                loop {
+                    let x = self.inner.sample(rng);
+
+                    // `-1.0 + 1.0` is `+0.0` even if the bounds are negative
+                    let x = if x == 0.0 && !crate::$type::accept_positive() {
+                        -0.0
+                    } else {
+                        x
+                    };
+
+                    // The zero is rejected if the bounds have different signs
+                    if let Ok(x) = $type::<$float>::new(x) {
+                        return x;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_rand!(NonNaN, StandardUniform);
+impl_rand!(NonZeroNonNaN, OpenClosed01);
+impl_rand!(NonNaNFinite, StandardUniform);
+impl_rand!(NonZeroNonNaNFinite, OpenClosed01);
+impl_rand!(Positive, StandardUniform);
+impl_rand!(Negative, StandardUniform, -);
+impl_rand!(PositiveFinite, StandardUniform);
+impl_rand!(NegativeFinite, StandardUniform, -);
+impl_rand!(StrictlyPositive, OpenClosed01);
+impl_rand!(StrictlyNegative, OpenClosed01, -);
+impl_rand!(StrictlyPositiveFinite, OpenClosed01);
+impl_rand!(StrictlyNegativeFinite, OpenClosed01, -);
@@ -0,0 +1,188 @@
+use ::rand::distr::Distribution;
+use ::rand::Rng;
+use ::rand_distr::{Exp, Exp1, Gamma, Normal, StandardNormal};
+
+use crate::types::{NonNaNFinite, Positive, PositiveFinite, StrictlyPositiveFinite};
+
+/// Infallible constructor of [`Exp`] from a typed parameter.
+pub trait ExpFromTyped<F>: Sized {
+    /// Creates the exponential distribution of rate `lambda`.
+    ///
+    /// Unlike [`Exp::new`], it can't fail as `lambda` is always valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use rand_distr::{Distribution, Exp};
+    /// use typed_floats::*;
+    ///
+    /// let lambda: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let exp = Exp::from_typed(lambda);
+    ///
+    /// let x: Positive = exp.sample(&mut SmallRng::seed_from_u64(0));
+    /// assert!(x >= 0.0);
+    /// ```
+    fn from_typed(lambda: StrictlyPositiveFinite<F>) -> Self;
+}
+
+/// Infallible constructor of [`Normal`] from typed parameters.
+pub trait NormalFromTyped<F>: Sized {
+    /// Creates the normal distribution of mean `mean` and standard deviation `std_dev`.
+    ///
+    /// Unlike [`Normal::new`], it can't fail as `std_dev` is always finite.
+    ///
+    /// [`Normal`] has no typed samples: it can also be created by [`Normal::new`]
+    /// with a `NaN` or infinite mean, and `∞ + -∞` is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use rand_distr::{Distribution, Normal};
+    /// use typed_floats::*;
+    ///
+    /// let mean: NonNaNFinite = 10.0.try_into().unwrap();
+    /// let std_dev: PositiveFinite = 2.0.try_into().unwrap();
+    /// let normal = Normal::from_typed(mean, std_dev);
+    ///
+    /// let x: f64 = normal.sample(&mut SmallRng::seed_from_u64(0));
+    /// assert!(!x.is_nan());
+    /// ```
+    fn from_typed(mean: NonNaNFinite<F>, std_dev: PositiveFinite<F>) -> Self;
+}
+
+/// Infallible constructor of [`Gamma`] from typed parameters.
+pub trait GammaFromTyped<F>: Sized {
+    /// Creates the gamma distribution of shape `shape` and scale `scale`.
+    ///
+    /// Unlike [`Gamma::new`], it can't fail as `shape` and `scale` are always valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use rand_distr::{Distribution, Gamma};
+    /// use typed_floats::*;
+    ///
+    /// let shape: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let scale: StrictlyPositiveFinite = 0.5.try_into().unwrap();
+    /// let gamma = Gamma::from_typed(shape, scale);
+    ///
+    /// let x: Positive = gamma.sample(&mut SmallRng::seed_from_u64(0));
+    /// assert!(x >= 0.0);
+    /// ```
+    fn from_typed(shape: StrictlyPositiveFinite<F>, scale: StrictlyPositiveFinite<F>) -> Self;
+}
+
+macro_rules! invalid_parameters {
+    ($name:ident) => {{
+        if cfg!(feature = "compiler_hints")
+            && !cfg!(any(
+                debug_assertions,
+                feature = "ensure_no_undefined_behavior"
+            ))
+        {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+
+        panic!(concat!(
+            "The parameters are not valid for ",
+            stringify!($name)
+        ))
+    }};
+}
+
+macro_rules! impl_rand_distr {
+    ($float:ident) => {
+        impl ExpFromTyped<$float> for Exp<$float> {
+            #[inline]
+            fn from_typed(lambda: StrictlyPositiveFinite<$float>) -> Self {
+                match Self::new(lambda.get()) {
+                    Ok(exp) => exp,
+                    // Only negative and `NaN` rates are rejected
+                    Err(_) => invalid_parameters!(Exp),
+                }
+            }
+        }
+
+        impl NormalFromTyped<$float> for Normal<$float> {
+            #[inline]
+            fn from_typed(mean: NonNaNFinite<$float>, std_dev: PositiveFinite<$float>) -> Self {
+                match Self::new(mean.get(), std_dev.get()) {
+                    Ok(normal) => normal,
+                    // Only non-finite standard deviations are rejected
+                    Err(_) => invalid_parameters!(Normal),
+                }
+            }
+        }
+
+        impl GammaFromTyped<$float> for Gamma<$float> {
+            #[inline]
+            fn from_typed(
+                shape: StrictlyPositiveFinite<$float>,
+                scale: StrictlyPositiveFinite<$float>,
+            ) -> Self {
+                match Self::new(shape.get(), scale.get()) {
+                    Ok(gamma) => gamma,
+                    // Only the non strictly positive parameters are rejected
+                    Err(_) => invalid_parameters!(Gamma),
+                }
+            }
+        }
+
+        impl Distribution<Positive<$float>> for Exp1 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$float> {
+                let x: $float = self.sample(rng);
+
+                // # Safety
+                // `Exp1` only samples values in `(0, ∞]`
+                unsafe { Positive::<$float>::new_unchecked(x) }
+            }
+        }
+
+        impl Distribution<NonNaNFinite<$float>> for StandardNormal {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNaNFinite<$float> {
+                let x: $float = self.sample(rng);
+
+                // # Safety
+                // `StandardNormal` only samples finite values
+                unsafe { NonNaNFinite::<$float>::new_unchecked(x) }
+            }
+        }
+
+        impl Distribution<Positive<$float>> for Exp<$float> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$float> {
+                loop {
+                    let x: $float = self.sample(rng);
+
+                    // `1 / rate` is `∞` when the rate is zero or subnormal, and `0.0 * ∞` is `NaN`, it is drawn again
+                    if let Ok(x) = Positive::<$float>::new(x) {
+                        return x;
+                    }
+                }
+            }
+        }
+
+        impl Distribution<Positive<$float>> for Gamma<$float> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$float> {
+                loop {
+                    let x: $float = self.sample(rng);
+
+                    // An infinite parameter samples `Exp1 * ∞`, and `0.0 * ∞` is `NaN`, it is drawn again
+                    if let Ok(x) = Positive::<$float>::new(x) {
+                        return x;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_rand_distr!(f64);
+impl_rand_distr!(f32);
@@ -0,0 +1,186 @@
+use crate::types::InvalidNumber;
+
+/// A value split by its sign, as returned by `split_sign`
+///
+/// The positive variant contains `+0.0` and `+inf`, the negative one `-0.0` and `-inf`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// let x: NonNaN = (-3.0).try_into().unwrap();
+///
+/// match x.split_sign() {
+///     Signed::Positive(x) => println!("{} is positive", x),
+///     Signed::Negative(x) => assert_eq!(-x, 3.0),
+/// }
+///
+/// let zero: Positive = 0.0.try_into().unwrap();
+/// assert_eq!(zero.refine_zero(), Err(PositiveZero));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Signed<P, N> {
+    /// A value with a positive sign
+    Positive(P),
+    /// A value with a negative sign
+    Negative(N),
+}
+
+impl<P: core::fmt::Display, N: core::fmt::Display> core::fmt::Display for Signed<P, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Positive(x) => x.fmt(f),
+            Self::Negative(x) => x.fmt(f),
+        }
+    }
+}
+
+#[rustversion::since(1.81)]
+impl<P: core::error::Error, N: core::error::Error> core::error::Error for Signed<P, N> {}
+
+#[rustversion::before(1.81)]
+#[cfg(feature = "std")]
+impl<P: std::error::Error, N: std::error::Error> std::error::Error for Signed<P, N> {}
+
+impl<P, N> From<Signed<P, N>> for InvalidNumber
+where
+    P: Into<Self>,
+    N: Into<Self>,
+{
+    #[inline]
+    fn from(value: Signed<P, N>) -> Self {
+        match value {
+            Signed::Positive(x) => x.into(),
+            Signed::Negative(x) => x.into(),
+        }
+    }
+}
+
+macro_rules! rejected_value {
+    ($name:ident, $doc:literal, $message:literal, $invalid:ident) => {
+        #[doc = $doc]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub struct $name;
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, $message)
+            }
+        }
+
+        #[rustversion::since(1.81)]
+        impl core::error::Error for $name {}
+
+        #[rustversion::before(1.81)]
+        #[cfg(feature = "std")]
+        impl std::error::Error for $name {}
+
+        impl From<$name> for InvalidNumber {
+            #[inline]
+            fn from(_: $name) -> Self {
+                Self::$invalid
+            }
+        }
+    };
+}
+
+rejected_value!(
+    PositiveZero,
+    "The rejected value when it can only be `+0.0`",
+    "Number is zero",
+    Zero
+);
+rejected_value!(
+    NegativeZero,
+    "The rejected value when it can only be `-0.0`",
+    "Number is zero",
+    Zero
+);
+rejected_value!(
+    PositiveInfinity,
+    "The rejected value when it can only be `+inf`",
+    "Number is infinite",
+    Infinite
+);
+rejected_value!(
+    NegativeInfinity,
+    "The rejected value when it can only be `-inf`",
+    "Number is infinite",
+    Infinite
+);
+
+/// A reason that can cause a [`Rejected`] error
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Possible;
+
+/// A reason that can't cause a [`Rejected`] error, it has no values
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Impossible {}
+
+/// The error of the conversions between the types of this crate
+///
+/// Each reason is [`Possible`] if the conversion can fail for that reason and [`Impossible`] otherwise,
+/// so only the possible reasons have to be handled.
+/// It can be converted into [`InvalidNumber`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// let x: PositiveFinite = 0.0.try_into().unwrap();
+///
+/// match StrictlyPositiveFinite::try_from(x) {
+///     Ok(x) => println!("{x} > 0"),
+///     Err(Rejected::Zero(Possible)) => println!("zero"),
+///     // Those arms can be omitted since Rust 1.82
+///     Err(Rejected::Infinite(never) | Rejected::Negative(never) | Rejected::Positive(never)) => {
+///         match never {}
+///     }
+/// }
+///
+/// let error: InvalidNumber = StrictlyPositiveFinite::try_from(x).unwrap_err().into();
+/// assert_eq!(error, InvalidNumber::Zero);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Rejected<I = Impossible, N = Impossible, P = Impossible, Z = Impossible> {
+    /// `+inf` or `-inf`
+    Infinite(I),
+    /// Any negative number, including `-0.0` and `-inf` when they are not already rejected
+    Negative(N),
+    /// Any positive number, including `+0.0` and `+inf` when they are not already rejected
+    Positive(P),
+    /// `+0.0` or `-0.0`
+    Zero(Z),
+}
+
+impl<I, N, P, Z> core::fmt::Display for Rejected<I, N, P, Z> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Infinite(_) => write!(f, "Number is infinite"),
+            Self::Negative(_) => write!(f, "Number is negative"),
+            Self::Positive(_) => write!(f, "Number is positive"),
+            Self::Zero(_) => write!(f, "Number is zero"),
+        }
+    }
+}
+
+#[rustversion::since(1.81)]
+impl<I, N, P, Z> core::error::Error for Rejected<I, N, P, Z> where Self: core::fmt::Debug {}
+
+#[rustversion::before(1.81)]
+#[cfg(feature = "std")]
+impl<I, N, P, Z> std::error::Error for Rejected<I, N, P, Z> where Self: core::fmt::Debug {}
+
+impl<I, N, P, Z> From<Rejected<I, N, P, Z>> for InvalidNumber {
+    #[inline]
+    fn from(value: Rejected<I, N, P, Z>) -> Self {
+        match value {
+            Rejected::Infinite(_) => Self::Infinite,
+            Rejected::Negative(_) => Self::Negative,
+            Rejected::Positive(_) => Self::Positive,
+            Rejected::Zero(_) => Self::Zero,
+        }
+    }
+}
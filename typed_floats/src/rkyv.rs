@@ -0,0 +1,145 @@
+use core::marker::PhantomData;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::primitive::{ArchivedF32, ArchivedF64};
+use rkyv::rancor::{Fallible, ResultExt, Source};
+use rkyv::traits::{CopyOptimization, NoUndef};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::types::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+/// An archived typed float `T`, stored as its primitive `A` (`f32` or `f64` in little-endian by default).
+///
+/// When the archive is validated (with [`rkyv::access`] for example), the value is checked to be accepted by `T`,
+/// so the guarantees of `T` hold even when reading untrusted data.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// let x: Positive<f32> = 3.0.try_into().unwrap();
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&x).unwrap();
+///
+/// let archived = rkyv::access::<rkyv::Archived<Positive<f32>>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived.to_native(), x);
+///
+/// let negative = (-3.0f32).to_le_bytes();
+/// assert!(rkyv::access::<rkyv::Archived<Positive<f32>>, rkyv::rancor::Error>(&negative).is_err());
+/// ```
+#[repr(transparent)]
+pub struct ArchivedTypedFloat<T, A> {
+    value: A,
+    kind: PhantomData<T>,
+}
+
+// # Safety
+// The struct is `repr(transparent)` over `A`, the `PhantomData` is never stored
+unsafe impl<T, A: Portable> Portable for ArchivedTypedFloat<T, A> {}
+
+// # Safety
+// The struct is `repr(transparent)` over `A`, the `PhantomData` is never stored
+unsafe impl<T, A: NoUndef> NoUndef for ArchivedTypedFloat<T, A> {}
+
+macro_rules! impl_rkyv {
+    ($type:ident) => {
+        impl_rkyv!($type, f64, ArchivedF64);
+        impl_rkyv!($type, f32, ArchivedF32);
+    };
+    ($type:ident, $float:ident, $archived:ident) => {
+        impl ArchivedTypedFloat<$type<$float>, $archived> {
+            /// Returns the native value
+            #[inline]
+            #[must_use]
+            pub const fn to_native(&self) -> $type<$float> {
+                // # Safety
+                // The value has been checked on access, or the caller of the unchecked access
+                // guaranteed that it is valid
+                unsafe { $type::<$float>::new_unchecked(self.value.to_native()) }
+            }
+        }
+
+        impl core::fmt::Debug for ArchivedTypedFloat<$type<$float>, $archived> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.to_native().fmt(f)
+            }
+        }
+
+        impl PartialEq<$type<$float>> for ArchivedTypedFloat<$type<$float>, $archived> {
+            #[inline]
+            fn eq(&self, other: &$type<$float>) -> bool {
+                self.to_native() == *other
+            }
+        }
+
+        impl Archive for $type<$float> {
+            // # Safety
+            // The type has the same layout as the primitive and archives the same way
+            const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe {
+                CopyOptimization::enable_if(<$float as Archive>::COPY_OPTIMIZATION.is_enabled())
+            };
+
+            type Archived = ArchivedTypedFloat<Self, $archived>;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+                out.write(ArchivedTypedFloat {
+                    value: $archived::from_native(self.get()),
+                    kind: PhantomData,
+                });
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $type<$float> {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$type<$float>, D>
+            for ArchivedTypedFloat<$type<$float>, $archived>
+        {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$type<$float>, D::Error> {
+                Ok(self.to_native())
+            }
+        }
+
+        // # Safety
+        // The value is only accepted if it is valid for the type
+        unsafe impl<C> CheckBytes<C> for ArchivedTypedFloat<$type<$float>, $archived>
+        where
+            C: Fallible + ?Sized,
+            C::Error: Source,
+        {
+            #[inline]
+            unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+                // # Safety
+                // The pointer is aligned and points to enough initialized bytes,
+                // and all the bit patterns are valid floats
+                let value = unsafe { value.read() }.value.to_native();
+
+                $type::<$float>::new(value).map(|_| ()).into_error()
+            }
+        }
+    };
+}
+
+impl_rkyv!(NonNaN);
+impl_rkyv!(NonZeroNonNaN);
+impl_rkyv!(NonNaNFinite);
+impl_rkyv!(NonZeroNonNaNFinite);
+impl_rkyv!(Positive);
+impl_rkyv!(Negative);
+impl_rkyv!(PositiveFinite);
+impl_rkyv!(NegativeFinite);
+impl_rkyv!(StrictlyPositive);
+impl_rkyv!(StrictlyNegative);
+impl_rkyv!(StrictlyPositiveFinite);
+impl_rkyv!(StrictlyNegativeFinite);
@@ -0,0 +1,149 @@
+//! Strict deserialization of the `f32` types, to use with `#[serde(with = "...")]`.
+//!
+//! The default implementations deserialize an `f32` directly, so `3.4e39` is rounded to `inf`
+//! and `1e-50` to `0.0` before being checked.
+//! These adapters deserialize an `f64` instead and reject the values that can't be converted without loss:
+//! - [`strict`] rejects the finite values that overflow to infinity and the non-zero values that underflow to zero.
+//! - [`exact`] also rejects the values that are not exactly representable as `f32`.
+//!
+//! The formats that are not human-readable, like `bincode` or `postcard`, store the `f32` itself:
+//! it is deserialized as is, as there is nothing to round.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_floats::*;
+//!
+//! #[derive(serde::Deserialize, Debug)]
+//! struct Config {
+//!     #[serde(with = "typed_floats::serde_f32::strict")]
+//!     gain: Positive<f32>,
+//!     #[serde(with = "typed_floats::serde_f32::exact")]
+//!     offset: NonNaNFinite<f32>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "gain": 0.1, "offset": 0.5 }"#).unwrap();
+//! assert_eq!(config.gain, 0.1f32);
+//!
+//! let error = serde_json::from_str::<Config>(r#"{ "gain": 3.4e39, "offset": 0.5 }"#).unwrap_err();
+//! assert!(error.to_string().contains("overflows to infinity in f32"));
+//!
+//! let error = serde_json::from_str::<Config>(r#"{ "gain": 1.0, "offset": 0.1 }"#).unwrap_err();
+//! assert!(error.to_string().starts_with("0.1 is not exactly representable in f32"));
+//! ```
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use crate::types::InvalidNumber;
+
+/// Converts the value to `f32`, rejecting the overflows and underflows, and the precision loss when `exact`.
+fn to_f32<E: Error>(value: f64, exact: bool) -> Result<f32, E> {
+    // The rounding is the point of the checks below
+    #[allow(clippy::cast_possible_truncation)]
+    let converted = value as f32;
+
+    if value.is_finite() && converted.is_infinite() {
+        return Err(E::custom(format_args!(
+            "{value:e} overflows to infinity in f32"
+        )));
+    }
+
+    if value != 0.0 && converted == 0.0 {
+        return Err(E::custom(format_args!(
+            "{value:e} underflows to zero in f32"
+        )));
+    }
+
+    if exact && !value.is_nan() && f64::from(converted).to_bits() != value.to_bits() {
+        return Err(E::custom(format_args!(
+            "{value} is not exactly representable in f32"
+        )));
+    }
+
+    Ok(converted)
+}
+
+fn deserialize_with<'de, D, T>(deserializer: D, exact: bool) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<f32, Error = InvalidNumber>,
+{
+    // The binary formats may not be self-describing, so an `f32` must be read as an `f32`
+    if !deserializer.is_human_readable() {
+        let value: f32 = Deserialize::deserialize(deserializer)?;
+
+        return T::try_from(value).map_err(Error::custom);
+    }
+
+    let value: f64 = Deserialize::deserialize(deserializer)?;
+
+    T::try_from(to_f32(value, exact)?).map_err(Error::custom)
+}
+
+/// Rejects the finite values that overflow to infinity and the non-zero values that underflow to zero.
+pub mod strict {
+    use super::{deserialize_with, InvalidNumber};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Deserializes an `f32` type from an `f64`, rejecting the overflows and underflows.
+    ///
+    /// The formats that are not human-readable are deserialized from an `f32`.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a number, overflows, underflows or is not accepted by the type
+    #[inline]
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<f32, Error = InvalidNumber>,
+    {
+        deserialize_with(deserializer, false)
+    }
+
+    /// Serializes the value as usual.
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails
+    #[inline]
+    pub fn serialize<S: Serializer, T: Serialize>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+}
+
+/// Rejects the values that are not exactly representable as `f32`, including overflows and underflows.
+pub mod exact {
+    use super::{deserialize_with, InvalidNumber};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Deserializes an `f32` type from an `f64`, rejecting the values that are not exactly representable.
+    ///
+    /// The formats that are not human-readable are deserialized from an `f32`.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a number, is not exactly representable as `f32`
+    /// or is not accepted by the type
+    #[inline]
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<f32, Error = InvalidNumber>,
+    {
+        deserialize_with(deserializer, true)
+    }
+
+    /// Serializes the value as usual.
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails
+    #[inline]
+    pub fn serialize<S: Serializer, T: Serialize>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+}
@@ -112,6 +112,30 @@ pub trait Copysign<T> {
     fn copysign(self, rhs: T) -> Self::Output;
 }
 
+/// This trait is used to specify the return type of the [`AbsSub::abs_sub()`] function.
+pub trait AbsSub<T> {
+    /// The resulting type after applying [`AbsSub::abs_sub()`].
+    type Output;
+
+    /// The positive difference of two numbers.
+    ///
+    /// * If `self <= other`: `0.0`
+    /// * Else: `self - other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: NonNaN = 3.0.try_into().unwrap();
+    /// let b: NonNaN = (-1.0).try_into().unwrap();
+    ///
+    /// assert_eq!(a.abs_sub(b), 4.0);
+    /// assert_eq!(b.abs_sub(a), 0.0);
+    /// assert_eq!(tf64::INFINITY.abs_sub(tf64::INFINITY), 0.0);
+    /// ```
+    fn abs_sub(self, rhs: T) -> Self::Output;
+}
+
 #[cfg(any(feature = "std", feature = "libm"))]
 /// This trait is used to specify the return type of the [`DivEuclid::div_euclid()`] function.
 pub trait DivEuclid<T> {
@@ -143,6 +167,35 @@ pub trait DivEuclid<T> {
     fn div_euclid(self, rhs: T) -> Self::Output;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`RemEuclid::rem_euclid()`] function.
+pub trait RemEuclid<T> {
+    /// The resulting type after applying [`RemEuclid::rem_euclid()`].
+    type Output;
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// In particular, the return value `r` satisfies `0.0 <= r < rhs.abs()` in
+    /// most cases. However, due to a floating point round-off error it can
+    /// result in `r == rhs.abs()`, violating the mathematical definition, if
+    /// `self` is much smaller than `rhs.abs()` in magnitude and `self < 0.0`.
+    /// A negative `self` can also give `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: NonNaNFinite = 7.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 4.0.try_into().unwrap();
+    ///
+    /// assert_eq!(a.rem_euclid(b), 3.0);
+    /// assert_eq!((-a).rem_euclid(b), 1.0);
+    /// ```
+    ///
+    /// See [`f64::rem_euclid()`] for more details.
+    fn rem_euclid(self, rhs: T) -> Self::Output;
+}
+
 #[cfg(any(feature = "std", feature = "libm"))]
 /// This trait is used to specify the return type of the [`Atan2::atan2()`] function.
 pub trait Atan2<T> {
@@ -191,6 +244,30 @@ pub trait Powf<T> {
     fn powf(self, rhs: T) -> Self::Output;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`Log::log()`] function.
+pub trait Log<T> {
+    /// The resulting type after applying [`Log::log()`].
+    type Output;
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// The result is always a primitive, as `1.0.log(1.0)` is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 8.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    ///
+    /// assert_relative_eq!(a.log(b), 3.0);
+    /// ```
+    ///
+    /// See [`f64::log()`] for more details.
+    fn log(self, base: T) -> Self::Output;
+}
+
 #[rustversion::since(1.85)]
 /// This trait is used to specify the return type of the [`Midpoint::midpoint()`] function.
 pub trait Midpoint<T> {
@@ -200,3 +277,29 @@ pub trait Midpoint<T> {
     /// See [`f64::midpoint()`] for more details.
     fn midpoint(self, rhs: T) -> Self::Output;
 }
+
+/// This trait is used to convert values of different types into the strictest type accepting both.
+pub trait CommonType<T> {
+    /// The strictest type accepting all the values of `Self` and `T`, the same for `T` and `Self`.
+    type Output;
+
+    /// Converts the value into the common type of `Self` and `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: Positive = 3.0.try_into().unwrap();
+    /// let b: NegativeFinite = (-2.0).try_into().unwrap();
+    ///
+    /// let v = if a > 2.0 {
+    ///     CommonType::<NegativeFinite>::into_common(a)
+    /// } else {
+    ///     CommonType::<Positive>::into_common(b)
+    /// };
+    ///
+    /// let v: NonNaN = v;
+    /// assert_eq!(v, 3.0);
+    /// ```
+    fn into_common(self) -> Self::Output;
+}
@@ -1,184 +1,28 @@
-macro_rules! accept_infinity {
-    (Negative) => {
-        true
-    };
-    (NegativeFinite) => {
-        false
-    };
-    (NonNaN) => {
-        true
-    };
-    (NonNaNFinite) => {
-        false
-    };
-    (NonZeroNonNaN) => {
-        true
-    };
-    (NonZeroNonNaNFinite) => {
-        false
-    };
-    (Positive) => {
-        true
-    };
-    (PositiveFinite) => {
-        false
-    };
-    (StrictlyNegative) => {
-        true
-    };
-    (StrictlyNegativeFinite) => {
-        false
-    };
-    (StrictlyPositive) => {
-        true
-    };
-    (StrictlyPositiveFinite) => {
-        false
-    };
-}
-
-macro_rules! accept_zero {
-    (Negative) => {
-        true
-    };
-    (NegativeFinite) => {
-        true
-    };
-    (NonNaN) => {
-        true
-    };
-    (NonNaNFinite) => {
-        true
-    };
-    (NonZeroNonNaN) => {
-        false
-    };
-    (NonZeroNonNaNFinite) => {
-        false
-    };
-    (Positive) => {
-        true
-    };
-    (PositiveFinite) => {
-        true
-    };
-    (StrictlyNegative) => {
-        false
-    };
-    (StrictlyNegativeFinite) => {
-        false
-    };
-    (StrictlyPositive) => {
-        false
-    };
-    (StrictlyPositiveFinite) => {
-        false
-    };
-}
-
-macro_rules! accept_negative {
-    (Negative) => {
-        true
-    };
-    (NegativeFinite) => {
-        true
-    };
-    (NonNaN) => {
-        true
-    };
-    (NonNaNFinite) => {
-        true
-    };
-    (NonZeroNonNaN) => {
-        true
-    };
-    (NonZeroNonNaNFinite) => {
-        true
-    };
-    (Positive) => {
-        false
-    };
-    (PositiveFinite) => {
-        false
-    };
-    (StrictlyNegative) => {
-        true
-    };
-    (StrictlyNegativeFinite) => {
-        true
-    };
-    (StrictlyPositive) => {
-        false
-    };
-    (StrictlyPositiveFinite) => {
-        false
-    };
-}
-
-macro_rules! accept_positive {
-    (Negative) => {
-        false
-    };
-    (NegativeFinite) => {
-        false
-    };
-    (NonNaN) => {
-        true
-    };
-    (NonNaNFinite) => {
-        true
-    };
-    (NonZeroNonNaN) => {
-        true
-    };
-    (NonZeroNonNaNFinite) => {
-        true
-    };
-    (Positive) => {
-        true
-    };
-    (PositiveFinite) => {
-        true
-    };
-    (StrictlyNegative) => {
-        false
-    };
-    (StrictlyNegativeFinite) => {
-        false
-    };
-    (StrictlyPositive) => {
-        true
-    };
-    (StrictlyPositiveFinite) => {
-        true
-    };
-}
-
 macro_rules! accept {
     ($type:ident) => {
         impl $type {
             /// Returns true if the type can accept infinity
             #[must_use]
             pub const fn accept_infinity() -> bool {
-                accept_infinity!($type)
+                FloatKind::$type.accept_infinity()
             }
 
             /// Returns true if the type can accept zero
             #[must_use]
             pub const fn accept_zero() -> bool {
-                accept_zero!($type)
+                FloatKind::$type.accept_zero()
             }
 
             /// Returns true if the type can accept negative values
             #[must_use]
             pub const fn accept_negative() -> bool {
-                accept_negative!($type)
+                FloatKind::$type.accept_negative()
             }
 
             /// Returns true if the type can accept positive values
             #[must_use]
             pub const fn accept_positive() -> bool {
-                accept_positive!($type)
+                FloatKind::$type.accept_positive()
             }
         }
 
@@ -210,6 +54,7 @@ macro_rules! accept {
     };
 }
 
+use crate::kind::FloatKind;
 use crate::types::{
     f32, f64, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite,
     Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
@@ -4,19 +4,58 @@ use crate::{
     StrictlyPositiveFinite,
 };
 
+/// Formats `$value` with every format string and compares with the primitive `$expected`
+#[cfg(test)]
+macro_rules! assert_same_format {
+    ($value:expr, $expected:expr, $($format:literal),*) => {
+        $(
+            assert_eq!(format!($format, $value), format!($format, $expected));
+        )*
+    };
+}
+
 macro_rules! impl_display {
     ($test:ident, $type:ident) => {
+        // The formatter is forwarded so the precision, width, fill and sign flags are kept
         impl core::fmt::Display for $type<f32> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}", self.0)
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerExp for $type<f32> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperExp for $type<f32> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperExp::fmt(&self.0, f)
             }
         }
 
         impl core::fmt::Display for $type<f64> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}", self.0)
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerExp for $type<f64> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperExp for $type<f64> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperExp::fmt(&self.0, f)
             }
         }
 
@@ -26,9 +65,27 @@ macro_rules! impl_display {
 
             for &value in &values_f32 {
                 if let Ok(t) = $type::<f32>::new(value) {
-                    let str1 = format!("{}", t);
-                    let str2 = format!("{}", value);
-                    assert_eq!(str1, str2);
+                    assert_same_format!(
+                        t,
+                        value,
+                        "{}",
+                        "{:.2}",
+                        "{:>10}",
+                        "{:<10}",
+                        "{:*^12.3}",
+                        "{:+}",
+                        "{:08.1}",
+                        "{:e}",
+                        "{:E}",
+                        "{:.3e}",
+                        "{:+E}",
+                        "{:>12.2e}"
+                    );
+
+                    let name = stringify!($type);
+                    assert_eq!(format!("{:?}", t), format!("{name}({:?})", value));
+                    assert_eq!(format!("{:.2?}", t), format!("{name}({:.2?})", value));
+                    assert_eq!(format!("{:#?}", t), format!("{name}(\n    {:?},\n)", value));
                 }
             }
 
@@ -36,9 +93,27 @@ macro_rules! impl_display {
 
             for &value in &values_f64 {
                 if let Ok(t) = $type::<f64>::new(value) {
-                    let str1 = format!("{}", t);
-                    let str2 = format!("{}", value);
-                    assert_eq!(str1, str2);
+                    assert_same_format!(
+                        t,
+                        value,
+                        "{}",
+                        "{:.2}",
+                        "{:>10}",
+                        "{:<10}",
+                        "{:*^12.3}",
+                        "{:+}",
+                        "{:08.1}",
+                        "{:e}",
+                        "{:E}",
+                        "{:.3e}",
+                        "{:+E}",
+                        "{:>12.2e}"
+                    );
+
+                    let name = stringify!($type);
+                    assert_eq!(format!("{:?}", t), format!("{name}({:?})", value));
+                    assert_eq!(format!("{:.2?}", t), format!("{name}({:.2?})", value));
+                    assert_eq!(format!("{:#?}", t), format!("{name}(\n    {:?},\n)", value));
                 }
             }
         }
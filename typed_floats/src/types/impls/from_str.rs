@@ -1,11 +1,69 @@
 use crate::{
-    FromStrError, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    FromStrError, FromStrExactError, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
     NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
     StrictlyPositive, StrictlyPositiveFinite,
 };
 
+/// Checks that the literal `s` has been parsed without overflow nor underflow.
+///
+/// `s` must be a valid float literal.
+fn check_exact(
+    s: &str,
+    is_infinite: bool,
+    is_zero_or_subnormal: bool,
+) -> Result<(), FromStrExactError> {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+
+    if is_infinite {
+        let is_literal_infinity =
+            unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity");
+
+        if !is_literal_infinity {
+            return Err(FromStrExactError::Overflow);
+        }
+    }
+
+    if is_zero_or_subnormal {
+        // Only the digits of the significand tell if the literal is zero
+        let significand = unsigned.split(['e', 'E']).next().unwrap_or(unsigned);
+        let is_literal_zero = !significand.bytes().any(|c| matches!(c, b'1'..=b'9'));
+
+        if !is_literal_zero {
+            return Err(FromStrExactError::Underflow);
+        }
+    }
+
+    Ok(())
+}
+
 macro_rules! impl_from_str {
     ($test:ident, $type:ident) => {
+        impl $type<f32> {
+            /// Parses a value like [`FromStr`](core::str::FromStr), but without rounding to infinity or zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// assert!(Positive::<f32>::from_str_exact("inf").is_ok());
+            /// assert_eq!(Positive::<f32>::from_str_exact("1e400"), Err(FromStrExactError::Overflow));
+            /// assert_eq!(StrictlyPositive::<f32>::from_str_exact("1e-400"), Err(FromStrExactError::Underflow));
+            /// ```
+            ///
+            /// # Errors
+            /// Returns an error if the string is not a valid number, if the value is not valid,
+            /// if a finite number overflows to infinity, or if a number different from zero
+            /// underflows to zero or to a subnormal number.
+            #[inline]
+            pub fn from_str_exact(s: &str) -> Result<Self, FromStrExactError> {
+                let f: f32 = s.parse::<f32>().map_err(FromStrError::ParseFloatError)?;
+
+                check_exact(s, f.is_infinite(), f == 0.0 || f.is_subnormal())?;
+
+                Ok(Self::try_from(f).map_err(FromStrError::InvalidNumber)?)
+            }
+        }
+
         impl core::str::FromStr for $type<f32> {
             type Err = FromStrError;
 
@@ -17,6 +75,32 @@ macro_rules! impl_from_str {
             }
         }
 
+        impl $type<f64> {
+            /// Parses a value like [`FromStr`](core::str::FromStr), but without rounding to infinity or zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// assert!(Positive::<f64>::from_str_exact("inf").is_ok());
+            /// assert_eq!(Positive::<f64>::from_str_exact("1e400"), Err(FromStrExactError::Overflow));
+            /// assert_eq!(StrictlyPositive::<f64>::from_str_exact("1e-400"), Err(FromStrExactError::Underflow));
+            /// ```
+            ///
+            /// # Errors
+            /// Returns an error if the string is not a valid number, if the value is not valid,
+            /// if a finite number overflows to infinity, or if a number different from zero
+            /// underflows to zero or to a subnormal number.
+            #[inline]
+            pub fn from_str_exact(s: &str) -> Result<Self, FromStrExactError> {
+                let f: f64 = s.parse::<f64>().map_err(FromStrError::ParseFloatError)?;
+
+                check_exact(s, f.is_infinite(), f == 0.0 || f.is_subnormal())?;
+
+                Ok(Self::try_from(f).map_err(FromStrError::InvalidNumber)?)
+            }
+        }
+
         impl core::str::FromStr for $type<f64> {
             type Err = FromStrError;
 
@@ -39,6 +123,13 @@ macro_rules! impl_from_str {
                     let t = str.parse::<$type<f32>>().unwrap();
 
                     assert_eq!(t.get(), value);
+
+                    let exact = $type::<f32>::from_str_exact(&str);
+                    if value.is_subnormal() {
+                        assert_eq!(exact, Err(FromStrExactError::Underflow));
+                    } else {
+                        assert_eq!(exact.unwrap(), t);
+                    }
                 }
             }
 
@@ -51,6 +142,13 @@ macro_rules! impl_from_str {
                     let t = str.parse::<$type<f64>>().unwrap();
 
                     assert_eq!(t.get(), value);
+
+                    let exact = $type::<f64>::from_str_exact(&str);
+                    if value.is_subnormal() {
+                        assert_eq!(exact, Err(FromStrExactError::Underflow));
+                    } else {
+                        assert_eq!(exact.unwrap(), t);
+                    }
                 }
             }
         }
@@ -69,3 +167,83 @@ impl_from_str!(strictly_positive, StrictlyPositive);
 impl_from_str!(strictly_negative, StrictlyNegative);
 impl_from_str!(strictly_positive_finite, StrictlyPositiveFinite);
 impl_from_str!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_from_str_exact() {
+    // Infinities are only accepted when written as such
+    for s in ["inf", "+inf", "-inf", "infinity", "-Infinity", "INF"] {
+        assert!(NonNaN::<f64>::from_str_exact(s).is_ok());
+        assert!(NonNaN::<f32>::from_str_exact(s).is_ok());
+    }
+
+    for s in ["1e400", "-1e400", "1.8e308", "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792"] {
+        assert_eq!(NonNaN::<f64>::from_str_exact(s), Err(FromStrExactError::Overflow));
+        assert_eq!(NonNaN::<f32>::from_str_exact(s), Err(FromStrExactError::Overflow));
+    }
+
+    for s in ["3.5e38", "-1e39"] {
+        assert!(NonNaN::<f64>::from_str_exact(s).is_ok());
+        assert_eq!(
+            NonNaN::<f32>::from_str_exact(s),
+            Err(FromStrExactError::Overflow)
+        );
+    }
+
+    // Zeros are only accepted when written as such
+    for s in ["0", "-0", "0.000", "0e400", "-0.0E-12", ".0"] {
+        assert!(NonNaN::<f64>::from_str_exact(s).is_ok());
+        assert!(NonNaN::<f32>::from_str_exact(s).is_ok());
+    }
+
+    for s in ["1e-400", "-1e-400", "4e-320", "0.00001e-310"] {
+        assert_eq!(
+            NonNaN::<f64>::from_str_exact(s),
+            Err(FromStrExactError::Underflow)
+        );
+        assert_eq!(
+            NonNaN::<f32>::from_str_exact(s),
+            Err(FromStrExactError::Underflow)
+        );
+    }
+
+    for s in ["1e-40", "-1e-45"] {
+        assert!(NonNaN::<f64>::from_str_exact(s).is_ok());
+        assert_eq!(
+            NonNaN::<f32>::from_str_exact(s),
+            Err(FromStrExactError::Underflow)
+        );
+    }
+
+    // The other errors are kept
+    assert!(matches!(
+        NonNaN::<f64>::from_str_exact("abc"),
+        Err(FromStrExactError::FromStrError(
+            FromStrError::ParseFloatError(_)
+        ))
+    ));
+    assert_eq!(
+        NonNaN::<f64>::from_str_exact("NaN"),
+        Err(FromStrExactError::FromStrError(
+            FromStrError::InvalidNumber(crate::InvalidNumber::NaN)
+        ))
+    );
+    assert_eq!(
+        StrictlyPositive::<f64>::from_str_exact("0"),
+        Err(FromStrExactError::FromStrError(
+            FromStrError::InvalidNumber(crate::InvalidNumber::Zero)
+        ))
+    );
+    assert_eq!(
+        PositiveFinite::<f64>::from_str_exact("inf"),
+        Err(FromStrExactError::FromStrError(
+            FromStrError::InvalidNumber(crate::InvalidNumber::Infinite)
+        ))
+    );
+
+    // `FromStr` is unchanged
+    assert_eq!("1e400".parse::<Positive<f64>>().unwrap(), f64::INFINITY);
+    assert_eq!(
+        "1e-400".parse::<StrictlyPositive<f64>>(),
+        Err(FromStrError::InvalidNumber(crate::InvalidNumber::Zero))
+    );
+}
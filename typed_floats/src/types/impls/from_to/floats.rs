@@ -20,6 +20,14 @@ macro_rules! impl_from {
             }
         }
 
+        impl From<$type<f32>> for $type<f64> {
+            #[inline]
+            fn from(value: $type<f32>) -> Self {
+                // Safety: the conversion from `f32` to `f64` is lossless, so the value keeps its kind
+                unsafe { Self::new_unchecked(f64::from(value.0)) }
+            }
+        }
+
         impl TryFrom<f32> for $type<f32> {
             type Error = InvalidNumber;
 
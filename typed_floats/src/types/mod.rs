@@ -1,7 +1,7 @@
 use const_fn::const_fn;
 
 /// An error that can occur when converting from a string into a typed float
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub enum FromStrError {
     /// The string did not contain a valid float number
     ParseFloatError(core::num::ParseFloatError),
@@ -21,6 +21,38 @@ impl core::fmt::Display for FromStrError {
 #[cfg(feature = "std")]
 impl std::error::Error for FromStrError {}
 
+/// An error that can occur when converting from a string into a typed float with `from_str_exact`
+#[derive(Debug, Eq, PartialEq)]
+pub enum FromStrExactError {
+    /// The string can't be converted, like with `FromStr`
+    FromStrError(FromStrError),
+    /// The string contained a finite number too large to be represented, it would be rounded to infinity
+    Overflow,
+    /// The string contained a number different from zero too small to be represented,
+    /// it would be rounded to zero or a subnormal number
+    Underflow,
+}
+
+impl core::fmt::Display for FromStrExactError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FromStrError(e) => write!(f, "{e}"),
+            Self::Overflow => write!(f, "Number is too large to be represented"),
+            Self::Underflow => write!(f, "Number is too small to be represented"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrExactError {}
+
+impl From<FromStrError> for FromStrExactError {
+    #[inline]
+    fn from(e: FromStrError) -> Self {
+        Self::FromStrError(e)
+    }
+}
+
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
@@ -51,9 +83,38 @@ impl core::fmt::Display for InvalidNumber {
     }
 }
 
+// Also needed without `std` by `rkyv`, whose `rancor::Source` errors must implement `core::error::Error`
+#[rustversion::since(1.81)]
+impl core::error::Error for InvalidNumber {}
+
+#[rustversion::before(1.81)]
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidNumber {}
 
+/// The error returned when a value is NaN, its only possible cause
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NaN;
+
+impl core::fmt::Display for NaN {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Number is NaN")
+    }
+}
+
+#[rustversion::since(1.81)]
+impl core::error::Error for NaN {}
+
+#[rustversion::before(1.81)]
+#[cfg(feature = "std")]
+impl std::error::Error for NaN {}
+
+impl From<NaN> for InvalidNumber {
+    #[inline]
+    fn from(_: NaN) -> Self {
+        Self::NaN
+    }
+}
+
 /// A non-NaN floating point number
 ///
 /// It satisfies the following constraints:
@@ -178,10 +239,10 @@ pub struct StrictlyPositiveFinite<T = f64>(T);
 #[repr(transparent)]
 pub struct StrictlyNegativeFinite<T = f64>(T);
 
-use crate::traits::{Max, Min};
+use crate::traits::{AbsSub, Max, Min};
 
 #[cfg(any(feature = "std", feature = "libm"))]
-use crate::traits::{Atan2, Copysign, DivEuclid, Hypot, Powf};
+use crate::traits::{Atan2, Copysign, DivEuclid, Hypot, Log, Powf, RemEuclid};
 
 #[rustversion::since(1.85)]
 use crate::traits::Midpoint;
@@ -0,0 +1,372 @@
+use ::ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::types::{
+    FromStrError, FromStrExactError, InvalidNumber, NaN, Negative, NegativeFinite, NonNaN,
+    NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative,
+    StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite,
+};
+
+/// Unsigned integer large enough to hold any finite `f64` scaled by a power of ten,
+/// used to compute the printed digits without rounding errors.
+#[derive(Clone, Copy)]
+struct BigUint {
+    // Little-endian, 1280 bits
+    limbs: [u32; 40],
+}
+
+impl BigUint {
+    const fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; 40];
+        // Splitting the value in two limbs
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            limbs[0] = value as u32;
+            limbs[1] = (value >> 32) as u32;
+        }
+        Self { limbs }
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            // Keeping the low bits, the high bits are carried
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                *limb = product as u32;
+            }
+            carry = product >> 32;
+        }
+        debug_assert_eq!(carry, 0);
+    }
+
+    fn mul_pow2(&mut self, exponent: u32) {
+        for _ in 0..exponent / 31 {
+            self.mul_small(1 << 31);
+        }
+        self.mul_small(1 << (exponent % 31));
+    }
+
+    fn mul_pow10(&mut self, exponent: u32) {
+        for _ in 0..exponent / 9 {
+            self.mul_small(1_000_000_000);
+        }
+        self.mul_small(10u32.pow(exponent % 9));
+    }
+
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (limb, &other) in self.limbs.iter_mut().zip(&other.limbs) {
+            let (value, overflow1) = limb.overflowing_sub(other);
+            let (value, overflow2) = value.overflowing_sub(u32::from(borrow));
+            *limb = value;
+            borrow = overflow1 || overflow2;
+        }
+        debug_assert!(!borrow);
+    }
+}
+
+impl PartialEq for BigUint {
+    fn eq(&self, other: &Self) -> bool {
+        self.limbs == other.limbs
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+/// Returns the `digits` first significant digits of a strictly positive finite value,
+/// and the exponent of the first one.
+///
+/// The digits are exact and rounded half to even, as with `format!("{:.*e}", digits - 1, value)`.
+fn decimal_digits(value: f64, digits: u32) -> (u64, i32) {
+    // `value` is exactly `significand * 2^binary_exponent`
+    let bits = value.to_bits();
+    // The sign bit is cleared, only the 11 bits of the exponent are left
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let biased_exponent = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (significand, binary_exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    // `floor(log2(value)) * log10(2)`, which is `floor(log10(value))` or one less
+    #[allow(clippy::cast_possible_wrap)]
+    let log2 = binary_exponent + 63 - significand.leading_zeros() as i32;
+    let mut exponent = (log2 * 78_913) >> 18;
+
+    // `value / 10^exponent` as the fraction `numerator / denominator`, in a single scaling step
+    let mut numerator = BigUint::from_u64(significand);
+    let mut denominator = BigUint::from_u64(1);
+    if binary_exponent > 0 {
+        numerator.mul_pow2(binary_exponent.unsigned_abs());
+    } else {
+        denominator.mul_pow2(binary_exponent.unsigned_abs());
+    }
+    if exponent > 0 {
+        denominator.mul_pow10(exponent.unsigned_abs());
+    } else {
+        numerator.mul_pow10(exponent.unsigned_abs());
+    }
+
+    let mut ten_denominators = denominator;
+    ten_denominators.mul_small(10);
+    if numerator >= ten_denominators {
+        exponent += 1;
+        denominator = ten_denominators;
+    }
+
+    // The fraction is in `[1, 10)`, its digits are extracted one by one
+    let mut mantissa: u64 = 0;
+    for k in 0..digits {
+        if k > 0 {
+            numerator.mul_small(10);
+        }
+        let mut digit = 0;
+        while numerator >= denominator {
+            numerator.sub(&denominator);
+            digit += 1;
+        }
+        mantissa = mantissa * 10 + digit;
+    }
+
+    // `numerator` is the remainder, compared to half of `denominator`
+    numerator.mul_small(2);
+    if numerator > denominator || (numerator == denominator && mantissa % 2 == 1) {
+        mantissa += 1;
+        if mantissa == 10u64.pow(digits) {
+            mantissa /= 10;
+            exponent += 1;
+        }
+    }
+
+    (mantissa, exponent)
+}
+
+/// Writes a non-NaN float with up to `digits` significant digits.
+///
+/// The values are written in decimal notation when the exponent is in `-5..digits`,
+/// and in scientific notation (`1.5e-7`) otherwise.
+/// Infinities are only checked when `FINITE` is `false`.
+fn write_float<const FINITE: bool, W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    value: f64,
+    digits: u32,
+) -> Result<(), W::Error> {
+    debug_assert!(!value.is_nan());
+
+    let value = if value.is_sign_negative() {
+        f.write_char('-')?;
+        -value
+    } else {
+        value
+    };
+
+    if !FINITE && value == f64::INFINITY {
+        return f.write_str("inf");
+    }
+
+    if value == 0.0 {
+        return f.write_char('0');
+    }
+
+    let (mut mantissa, exponent) = decimal_digits(value, digits);
+
+    let mut len = digits;
+    while len > 1 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        len -= 1;
+    }
+
+    let digit = |k: u32| -> char {
+        let value = len
+            .checked_sub(k + 1)
+            .map_or(0, |shift| mantissa / 10u64.pow(shift) % 10);
+        // `value` is a single digit
+        #[allow(clippy::cast_possible_truncation)]
+        char::from(b'0' + value as u8)
+    };
+
+    #[allow(clippy::cast_possible_wrap)]
+    let scientific = exponent < -5 || exponent >= digits as i32;
+
+    if scientific {
+        f.write_char(digit(0))?;
+        if len > 1 {
+            f.write_char('.')?;
+            for k in 1..len {
+                f.write_char(digit(k))?;
+            }
+        }
+        f.write_char('e')?;
+        if exponent < 0 {
+            f.write_char('-')?;
+        }
+        write_integer(f, exponent.unsigned_abs())
+    } else if let Ok(exponent) = u32::try_from(exponent) {
+        let integer_len = exponent + 1;
+        for k in 0..len.max(integer_len) {
+            if k == integer_len {
+                f.write_char('.')?;
+            }
+            f.write_char(digit(k))?;
+        }
+        Ok(())
+    } else {
+        f.write_str("0.")?;
+        for _ in 1..exponent.unsigned_abs() {
+            f.write_char('0')?;
+        }
+        for k in 0..len {
+            f.write_char(digit(k))?;
+        }
+        Ok(())
+    }
+}
+
+fn write_integer<W: uWrite + ?Sized>(f: &mut Formatter<'_, W>, value: u32) -> Result<(), W::Error> {
+    let mut divisor = 1;
+    while divisor * 10 <= value {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        // `value / divisor % 10` is a single digit
+        #[allow(clippy::cast_possible_truncation)]
+        f.write_char(char::from(b'0' + (value / divisor % 10) as u8))?;
+        divisor /= 10;
+    }
+    Ok(())
+}
+
+impl uDisplay for InvalidNumber {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            Self::NaN => "Number is NaN",
+            Self::Zero => "Number is zero",
+            Self::Negative => "Number is negative",
+            Self::Positive => "Number is positive",
+            Self::Infinite => "Number is infinite",
+        })
+    }
+}
+
+impl uDebug for InvalidNumber {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            Self::NaN => "NaN",
+            Self::Zero => "Zero",
+            Self::Negative => "Negative",
+            Self::Positive => "Positive",
+            Self::Infinite => "Infinite",
+        })
+    }
+}
+
+impl uDisplay for NaN {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("Number is NaN")
+    }
+}
+
+impl uDebug for NaN {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("NaN")
+    }
+}
+
+/// `ParseFloatError` only exposes its kind through `core::fmt`, but it can be compared
+fn is_empty_error(error: &core::num::ParseFloatError) -> bool {
+    "".parse::<f64>().err().as_ref() == Some(error)
+}
+
+impl uDisplay for FromStrError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::ParseFloatError(e) if is_empty_error(e) => {
+                f.write_str("cannot parse float from empty string")
+            }
+            Self::ParseFloatError(_) => f.write_str("invalid float literal"),
+            Self::InvalidNumber(e) => uDisplay::fmt(e, f),
+        }
+    }
+}
+
+impl uDebug for FromStrError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::ParseFloatError(e) if is_empty_error(e) => f.write_str("ParseFloatError(Empty)"),
+            Self::ParseFloatError(_) => f.write_str("ParseFloatError(Invalid)"),
+            Self::InvalidNumber(e) => {
+                f.write_str("InvalidNumber(")?;
+                uDebug::fmt(e, f)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+impl uDisplay for FromStrExactError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::FromStrError(e) => uDisplay::fmt(e, f),
+            Self::Overflow => f.write_str("Number is too large to be represented"),
+            Self::Underflow => f.write_str("Number is too small to be represented"),
+        }
+    }
+}
+
+impl uDebug for FromStrExactError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::FromStrError(e) => {
+                f.write_str("FromStrError(")?;
+                uDebug::fmt(e, f)?;
+                f.write_char(')')
+            }
+            Self::Overflow => f.write_str("Overflow"),
+            Self::Underflow => f.write_str("Underflow"),
+        }
+    }
+}
+
+macro_rules! impl_ufmt {
+    ($type:ident, $finite:literal) => {
+        impl_ufmt!($type, $finite, f64, 15);
+        impl_ufmt!($type, $finite, f32, 6);
+    };
+    ($type:ident, $finite:literal, $float:ident, $digits:literal) => {
+        impl uDisplay for $type<$float> {
+            #[inline]
+            fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                write_float::<$finite, W>(f, f64::from(self.get()), $digits)
+            }
+        }
+
+        impl uDebug for $type<$float> {
+            #[inline]
+            fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                f.write_str(concat!(stringify!($type), "("))?;
+                write_float::<$finite, W>(f, f64::from(self.get()), $digits)?;
+                f.write_char(')')
+            }
+        }
+    };
+}
+
+impl_ufmt!(NonNaN, false);
+impl_ufmt!(NonZeroNonNaN, false);
+impl_ufmt!(NonNaNFinite, true);
+impl_ufmt!(NonZeroNonNaNFinite, true);
+impl_ufmt!(Positive, false);
+impl_ufmt!(Negative, false);
+impl_ufmt!(PositiveFinite, true);
+impl_ufmt!(NegativeFinite, true);
+impl_ufmt!(StrictlyPositive, false);
+impl_ufmt!(StrictlyNegative, false);
+impl_ufmt!(StrictlyPositiveFinite, true);
+impl_ufmt!(StrictlyNegativeFinite, true);
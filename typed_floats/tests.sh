@@ -35,3 +35,20 @@ cargo test --release --no-default-features --features serde,std
 cargo test --release --no-default-features --features serde,libm
 cargo test --release --no-default-features --features std,libm
 cargo test --release --no-default-features --features serde,std,libm
+
+cargo test --features arbitrary --test arbitrary
+cargo test --features proptest --test proptest
+cargo test --features quickcheck --test quickcheck
+cargo test --features defmt --test defmt
+
+# The dependencies of those tests require a newer Rust than the MSRV
+cd ../integration_tests
+
+cargo test --features rand --test rand
+cargo test --features rand_distr --test rand_distr
+cargo test --features borsh --test borsh
+cargo test --features borsh_schema --test borsh
+cargo test --no-default-features --features borsh --test borsh
+cargo test --features rkyv --test rkyv
+cargo test --features ufmt --test ufmt
+cargo test --features serde --test serde
@@ -0,0 +1,3 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(abs_sub);
@@ -0,0 +1,104 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use typed_floats::*;
+
+/// Deterministic pseudo-random bytes, to have the same data on every run
+fn get_bytes(len: usize) -> Vec<u8> {
+    let mut state: u64 = 0x853C_49E6_748F_EA9B;
+
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state.to_be_bytes()[0]
+        })
+        .collect()
+}
+
+macro_rules! impl_arbitrary_test {
+    ($test:ident, $type:ident) => {
+        mod $test {
+            use super::*;
+
+            fn generate<'a, T: Arbitrary<'a>>(bytes: &'a [u8]) -> Vec<T> {
+                let mut u = Unstructured::new(bytes);
+                let mut values = Vec::new();
+
+                while !u.is_empty() {
+                    values.push(T::arbitrary(&mut u).expect("Failed to generate a value"));
+                }
+
+                values
+            }
+
+            #[test]
+            fn f64() {
+                let bytes = get_bytes(100_000);
+                let values: Vec<$type<f64>> = generate(&bytes);
+
+                for value in &values {
+                    assert!($type::<f64>::new(value.get()).is_ok(), "{value:?}");
+                }
+
+                // All the edge values accepted by the type are generated
+                for edge in tf64::get_test_values() {
+                    if $type::<f64>::new(edge).is_ok() {
+                        assert!(
+                            values.iter().any(|x| x.get().to_bits() == edge.to_bits()),
+                            "{edge:?} is never generated"
+                        );
+                    }
+                }
+
+                // Edge cases are also valid
+                for bytes in [[0x00; 16], [0xFF; 16], [0x7F; 16], [0x80; 16]] {
+                    for value in generate::<$type<f64>>(&bytes) {
+                        assert!($type::<f64>::new(value.get()).is_ok(), "{value:?}");
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                let bytes = get_bytes(100_000);
+                let values: Vec<$type<f32>> = generate(&bytes);
+
+                for value in &values {
+                    assert!($type::<f32>::new(value.get()).is_ok(), "{value:?}");
+                }
+
+                // All the edge values accepted by the type are generated
+                for edge in tf32::get_test_values() {
+                    if $type::<f32>::new(edge).is_ok() {
+                        assert!(
+                            values.iter().any(|x| x.get().to_bits() == edge.to_bits()),
+                            "{edge:?} is never generated"
+                        );
+                    }
+                }
+
+                // Edge cases are also valid
+                for bytes in [[0x00; 8], [0xFF; 8], [0x7F; 8], [0x80; 8]] {
+                    for value in generate::<$type<f32>>(&bytes) {
+                        assert!($type::<f32>::new(value.get()).is_ok(), "{value:?}");
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_arbitrary_test!(non_nan, NonNaN);
+impl_arbitrary_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_arbitrary_test!(non_nan_finite, NonNaNFinite);
+impl_arbitrary_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_arbitrary_test!(positive, Positive);
+impl_arbitrary_test!(negative, Negative);
+impl_arbitrary_test!(positive_finite, PositiveFinite);
+impl_arbitrary_test!(negative_finite, NegativeFinite);
+impl_arbitrary_test!(strictly_positive, StrictlyPositive);
+impl_arbitrary_test!(strictly_negative, StrictlyNegative);
+impl_arbitrary_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_arbitrary_test!(strictly_negative_finite, StrictlyNegativeFinite);
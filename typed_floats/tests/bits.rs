@@ -0,0 +1,150 @@
+use typed_floats::*;
+
+macro_rules! check_bits {
+    ($type:ident, $float:ident, $module:ident) => {
+        for x in $module::get_test_values() {
+            let bits = x.to_bits();
+
+            match $type::<$float>::new(x) {
+                Ok(typed) => {
+                    assert_eq!(typed.to_bits(), bits);
+                    assert_eq!(typed.to_le_bytes(), x.to_le_bytes());
+                    assert_eq!(typed.to_be_bytes(), x.to_be_bytes());
+                    assert_eq!(typed.to_ne_bytes(), x.to_ne_bytes());
+
+                    let decoded = [
+                        $type::<$float>::try_from_bits(bits),
+                        $type::<$float>::try_from_le_bytes(x.to_le_bytes()),
+                        $type::<$float>::try_from_be_bytes(x.to_be_bytes()),
+                        $type::<$float>::try_from_ne_bytes(x.to_ne_bytes()),
+                    ];
+
+                    for decoded in decoded {
+                        assert_eq!(decoded.expect("valid value").to_bits(), bits);
+                    }
+                }
+                Err(_) => {
+                    let expected = || $type::<$float>::new(x);
+
+                    assert_eq!($type::<$float>::try_from_bits(bits), expected());
+                    assert_eq!(
+                        $type::<$float>::try_from_le_bytes(x.to_le_bytes()),
+                        expected()
+                    );
+                    assert_eq!(
+                        $type::<$float>::try_from_be_bytes(x.to_be_bytes()),
+                        expected()
+                    );
+                    assert_eq!(
+                        $type::<$float>::try_from_ne_bytes(x.to_ne_bytes()),
+                        expected()
+                    );
+                }
+            }
+        }
+    };
+}
+
+macro_rules! check_ordered_bits {
+    ($type:ident, $float:ident, $module:ident) => {
+        let values = $module::get_test_values()
+            .into_iter()
+            .filter_map(|x| $type::<$float>::new(x).ok())
+            .collect::<Vec<_>>();
+
+        for a in &values {
+            let key = a.to_ordered_bits();
+
+            assert_eq!($type::<$float>::try_from_ordered_bits(key), Ok(*a));
+            assert_eq!(
+                $type::<$float>::try_from_ordered_bytes(a.to_ordered_bytes()),
+                Ok(*a)
+            );
+
+            for b in &values {
+                assert_eq!(key.cmp(&b.to_ordered_bits()), a.cmp(b));
+                assert_eq!(a.to_ordered_bytes().cmp(&b.to_ordered_bytes()), a.cmp(b));
+            }
+        }
+    };
+}
+
+macro_rules! impl_bits_test {
+    ($test:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            check_bits!($type, f64, tf64);
+            check_bits!($type, f32, tf32);
+            check_ordered_bits!($type, f64, tf64);
+            check_ordered_bits!($type, f32, tf32);
+        }
+    };
+}
+
+impl_bits_test!(non_nan, NonNaN);
+impl_bits_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_bits_test!(non_nan_finite, NonNaNFinite);
+impl_bits_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_bits_test!(positive, Positive);
+impl_bits_test!(negative, Negative);
+impl_bits_test!(positive_finite, PositiveFinite);
+impl_bits_test!(negative_finite, NegativeFinite);
+impl_bits_test!(strictly_positive, StrictlyPositive);
+impl_bits_test!(strictly_negative, StrictlyNegative);
+impl_bits_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_bits_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn nan() {
+    assert_eq!(
+        NonNaN::<f64>::try_from_bits(f64::NAN.to_bits()),
+        Err(InvalidNumber::NaN)
+    );
+    assert_eq!(
+        StrictlyPositiveFinite::<f32>::try_from_be_bytes(f32::NAN.to_be_bytes()),
+        Err(InvalidNumber::NaN)
+    );
+}
+
+#[test]
+fn network_buffer() {
+    let buffer = [0x40, 0x49, 0x0f, 0xdb];
+
+    let x = StrictlyPositiveFinite::<f32>::try_from_be_bytes(buffer).expect("valid value");
+
+    assert_eq!(x, core::f32::consts::PI);
+}
+
+#[test]
+fn ordered_zeros() {
+    let zero = NonNaN::<f64>::new(0.0).expect("valid value");
+    let neg_zero = NonNaN::<f64>::new(-0.0).expect("valid value");
+
+    assert_eq!(zero.to_ordered_bits(), neg_zero.to_ordered_bits());
+
+    let neg_zero = Negative::<f32>::new(-0.0).expect("valid value");
+
+    assert!(Negative::<f32>::try_from_ordered_bits(neg_zero.to_ordered_bits()).is_ok());
+}
+
+#[test]
+fn ordered_nan() {
+    let key = NonNaN::<f64>::new(f64::INFINITY)
+        .expect("valid value")
+        .to_ordered_bits();
+
+    assert_eq!(
+        NonNaN::<f64>::try_from_ordered_bits(key + 1),
+        Err(InvalidNumber::NaN)
+    );
+}
+
+#[rustversion::since(1.83)]
+#[test]
+fn constants() {
+    const BITS: u64 = tf64::consts::PI.to_bits();
+    const X: Result<StrictlyPositiveFinite, InvalidNumber> =
+        StrictlyPositiveFinite::<f64>::try_from_bits(BITS);
+
+    assert_eq!(X, Ok(tf64::consts::PI));
+}
@@ -0,0 +1,87 @@
+use typed_floats::*;
+
+#[test]
+fn test_f64() {
+    for x in tf64::get_test_values() {
+        let Ok(classified) = tf64::classify_strictest(x) else {
+            assert!(x.is_nan());
+            assert_eq!(Classified::<f64>::try_from(x), Err(NaN));
+            continue;
+        };
+
+        assert_eq!(classified.get().to_bits(), x.to_bits());
+        assert_eq!(f64::from(classified).to_bits(), x.to_bits());
+
+        let kind = classified.kind();
+        assert!(kind.accepts(x), "{x:?}");
+
+        // No other kind accepting the value is stricter
+        for other in FloatKind::ALL {
+            if other.accepts(x) {
+                assert!(kind.can_fit_into(other), "{x:?} {other:?}");
+            }
+        }
+
+        let non_nan = NonNaN::<f64>::from(classified);
+        assert_eq!(Classified::from(non_nan), classified);
+    }
+}
+
+#[test]
+fn test_f32() {
+    for x in tf32::get_test_values() {
+        let Ok(classified) = tf32::classify_strictest(x) else {
+            assert!(x.is_nan());
+            assert_eq!(Classified::<f32>::try_from(x), Err(NaN));
+            continue;
+        };
+
+        assert_eq!(classified.get().to_bits(), x.to_bits());
+        assert_eq!(f32::from(classified).to_bits(), x.to_bits());
+
+        let kind = classified.kind();
+        assert!(kind.accepts(f64::from(x)), "{x:?}");
+
+        for other in FloatKind::ALL {
+            if other.accepts(f64::from(x)) {
+                assert!(kind.can_fit_into(other), "{x:?} {other:?}");
+            }
+        }
+
+        let non_nan = NonNaN::<f32>::from(classified);
+        assert_eq!(Classified::from(non_nan), classified);
+    }
+}
+
+#[test]
+fn test_variants() {
+    assert!(matches!(
+        tf64::classify_strictest(0.0),
+        Ok(Classified::PositiveZero(_))
+    ));
+    assert!(matches!(
+        tf64::classify_strictest(-0.0),
+        Ok(Classified::NegativeZero(_))
+    ));
+    assert!(matches!(
+        tf64::classify_strictest(f64::INFINITY),
+        Ok(Classified::PositiveInfinity(_))
+    ));
+    assert!(matches!(
+        tf64::classify_strictest(f64::NEG_INFINITY),
+        Ok(Classified::NegativeInfinity(_))
+    ));
+    assert!(matches!(
+        tf64::classify_strictest(f64::MIN_POSITIVE / 2.0),
+        Ok(Classified::StrictlyPositiveFinite(_))
+    ));
+    assert!(matches!(
+        tf32::classify_strictest(f32::MIN),
+        Ok(Classified::StrictlyNegativeFinite(_))
+    ));
+
+    assert_ne!(
+        tf64::classify_strictest(0.0),
+        tf64::classify_strictest(-0.0)
+    );
+}
@@ -0,0 +1,74 @@
+use typed_floats::*;
+
+macro_rules! check_common_type {
+    ($a:ident, $b:ident, $float:ident, $module:ident) => {{
+        type Output = <$a<$float> as CommonType<$b<$float>>>::Output;
+
+        assert_eq!(
+            FloatKind::of::<Output>(),
+            FloatKind::$a.join(FloatKind::$b),
+            "{} and {}",
+            stringify!($a),
+            stringify!($b),
+        );
+        assert_eq!(
+            FloatKind::of::<Output>(),
+            FloatKind::of::<<$b<$float> as CommonType<$a<$float>>>::Output>(),
+        );
+
+        for x in $module::get_test_values() {
+            if let Ok(a) = $a::<$float>::new(x) {
+                let common: Output = CommonType::<$b<$float>>::into_common(a);
+                assert_eq!(common.get().to_bits(), x.to_bits());
+            }
+        }
+    }};
+}
+
+macro_rules! check_all_pairs {
+    ([$($a:ident),*], $b:tt) => {
+        $(
+            check_all_pairs!(@one $a, $b);
+        )*
+    };
+    (@one $a:ident, [$($b:ident),*]) => {
+        $(
+            check_common_type!($a, $b, f64, tf64);
+            check_common_type!($a, $b, f32, tf32);
+        )*
+    };
+}
+
+#[test]
+fn all_pairs() {
+    check_all_pairs!(
+        [
+            NonNaN,
+            NonZeroNonNaN,
+            NonNaNFinite,
+            NonZeroNonNaNFinite,
+            Positive,
+            Negative,
+            PositiveFinite,
+            NegativeFinite,
+            StrictlyPositive,
+            StrictlyNegative,
+            StrictlyPositiveFinite,
+            StrictlyNegativeFinite
+        ],
+        [
+            NonNaN,
+            NonZeroNonNaN,
+            NonNaNFinite,
+            NonZeroNonNaNFinite,
+            Positive,
+            Negative,
+            PositiveFinite,
+            NegativeFinite,
+            StrictlyPositive,
+            StrictlyNegative,
+            StrictlyPositiveFinite,
+            StrictlyNegativeFinite
+        ]
+    );
+}
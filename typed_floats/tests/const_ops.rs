@@ -0,0 +1,111 @@
+#[rustversion::since(1.83)]
+mod const_ops {
+    use typed_floats::*;
+
+    const TWO_PI: StrictlyPositive = tf64::consts::PI.const_add(tf64::consts::PI);
+    const PI_SQUARED: Positive = tf64::consts::PI.const_mul(tf64::consts::PI);
+    const MINUS_PI: StrictlyNegativeFinite = tf64::consts::PI.const_neg();
+    const HALF: Positive = tf64::consts::PI.const_div(tf64::consts::TAU);
+    const INV_PI: StrictlyPositive = tf64::consts::PI.const_recip();
+
+    // Only `Self` is accepted as right-hand side, another type is converted first
+    const TWO: PositiveFinite = as_const!(PositiveFinite, 2.0);
+    const HALF_PI: Positive =
+        tf64::consts::PI.const_div(as_const!(StrictlyPositiveFinite, TWO.get()));
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(TWO_PI, tf64::consts::TAU);
+        assert_eq!(PI_SQUARED, tf64::consts::PI * tf64::consts::PI);
+        assert_eq!(MINUS_PI, -core::f64::consts::PI);
+        assert_eq!(HALF, 0.5);
+        assert_eq!(INV_PI, core::f64::consts::FRAC_1_PI);
+        assert_eq!(HALF_PI, tf64::consts::FRAC_PI_2);
+    }
+
+    #[rustversion::since(1.85)]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_const_abs() {
+        const PI: StrictlyPositiveFinite = tf64::consts::PI.const_neg().const_abs();
+        assert_eq!(PI, tf64::consts::PI);
+
+        for a in tf64::get_test_values() {
+            // The arrays check that both have the same type
+            if let Ok(a) = NonNaN::<f64>::new(a) {
+                let [const_abs, abs] = [a.const_abs(), a.abs()];
+                assert_eq!(const_abs.get().to_bits(), abs.get().to_bits());
+            }
+            if let Ok(a) = Negative::<f64>::new(a) {
+                let [const_abs, abs] = [a.const_abs(), a.abs()];
+                assert_eq!(const_abs.get().to_bits(), abs.get().to_bits());
+            }
+        }
+    }
+
+    /// Checks that the `const` functions return the same value and type as the operators
+    macro_rules! impl_const_ops_test {
+        ($test:ident, $type:ident, $float:ident, $values:path) => {
+            #[test]
+            fn $test() {
+                for a in $values() {
+                    let Ok(a) = $type::<$float>::new(a) else {
+                        continue;
+                    };
+
+                    let neg: <$type<$float> as core::ops::Neg>::Output = a.const_neg();
+                    assert_eq!(neg, -a);
+
+                    // The array checks that both have the same type
+                    let [const_recip, recip] = [a.const_recip(), a.recip()];
+                    assert_eq!(const_recip.get().to_bits(), recip.get().to_bits());
+
+                    for b in $values() {
+                        let Ok(b) = $type::<$float>::new(b) else {
+                            continue;
+                        };
+
+                        let add: <$type<$float> as core::ops::Add>::Output = a.const_add(b);
+                        let sub: <$type<$float> as core::ops::Sub>::Output = a.const_sub(b);
+                        let mul: <$type<$float> as core::ops::Mul>::Output = a.const_mul(b);
+                        let div: <$type<$float> as core::ops::Div>::Output = a.const_div(b);
+                        let rem: <$type<$float> as core::ops::Rem>::Output = a.const_rem(b);
+
+                        let pairs: [($float, $float); 5] = [
+                            (add.into(), (a + b).into()),
+                            (sub.into(), (a - b).into()),
+                            (mul.into(), (a * b).into()),
+                            (div.into(), (a / b).into()),
+                            (rem.into(), (a % b).into()),
+                        ];
+
+                        for (const_result, result) in pairs {
+                            assert_eq!(const_result.to_bits(), result.to_bits());
+                        }
+                    }
+                }
+            }
+        };
+        ($test:ident, $type:ident) => {
+            mod $test {
+                use super::*;
+
+                impl_const_ops_test!(f64, $type, f64, tf64::get_test_values);
+                impl_const_ops_test!(f32, $type, f32, tf32::get_test_values);
+            }
+        };
+    }
+
+    impl_const_ops_test!(non_nan, NonNaN);
+    impl_const_ops_test!(non_zero_non_nan, NonZeroNonNaN);
+    impl_const_ops_test!(non_nan_finite, NonNaNFinite);
+    impl_const_ops_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+    impl_const_ops_test!(positive, Positive);
+    impl_const_ops_test!(negative, Negative);
+    impl_const_ops_test!(positive_finite, PositiveFinite);
+    impl_const_ops_test!(negative_finite, NegativeFinite);
+    impl_const_ops_test!(strictly_positive, StrictlyPositive);
+    impl_const_ops_test!(strictly_negative, StrictlyNegative);
+    impl_const_ops_test!(strictly_positive_finite, StrictlyPositiveFinite);
+    impl_const_ops_test!(strictly_negative_finite, StrictlyNegativeFinite);
+}
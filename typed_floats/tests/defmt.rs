@@ -0,0 +1,88 @@
+#![cfg(feature = "defmt")]
+
+use core::cell::RefCell;
+
+use typed_floats::*;
+
+const fn assert_format<T: defmt::Format>() {}
+
+std::thread_local! {
+    static BYTES: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+// Records the encoded frames of the current test
+#[defmt::global_logger]
+struct Logger;
+
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        BYTES.with(|b| b.borrow_mut().extend_from_slice(bytes));
+    }
+}
+
+defmt::timestamp!("");
+
+fn encode<T: defmt::Format>(value: T) -> Vec<u8> {
+    defmt::println!("{}", value);
+
+    BYTES.with(|b| core::mem::take(&mut *b.borrow_mut()))
+}
+
+// The tag, prefixed by its length as a `u32`, followed by the value
+#[allow(clippy::cast_possible_truncation)]
+fn contains_tagged(bytes: &[u8], tag: &str, value: &[u8]) -> bool {
+    let mut expected = (tag.len() as u32).to_le_bytes().to_vec();
+    expected.extend_from_slice(tag.as_bytes());
+    expected.extend_from_slice(value);
+
+    bytes.windows(expected.len()).any(|w| w == expected)
+}
+
+macro_rules! impl_defmt_test {
+    ($test:ident, $type:ident, $tag:literal, $value:expr) => {
+        #[test]
+        fn $test() {
+            assert_format::<$type<f64>>();
+            assert_format::<$type<f32>>();
+            assert_format::<$type>();
+
+            let x = $type::<f64>::new($value).unwrap();
+            assert!(contains_tagged(&encode(x), $tag, &x.get().to_le_bytes()));
+
+            let x = $type::<f32>::new($value).unwrap();
+            assert!(contains_tagged(&encode(x), $tag, &x.get().to_le_bytes()));
+        }
+    };
+}
+
+impl_defmt_test!(non_nan, NonNaN, "nn", 1.5);
+impl_defmt_test!(non_zero_non_nan, NonZeroNonNaN, "nznn", -1.5);
+impl_defmt_test!(non_nan_finite, NonNaNFinite, "nnf", 0.0);
+impl_defmt_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite, "nznnf", 2.5);
+impl_defmt_test!(positive, Positive, "p", 4.0);
+impl_defmt_test!(negative, Negative, "n", -0.0);
+impl_defmt_test!(positive_finite, PositiveFinite, "pf", 3.0);
+impl_defmt_test!(negative_finite, NegativeFinite, "nf", -3.0);
+impl_defmt_test!(strictly_positive, StrictlyPositive, "sp", 0.5);
+impl_defmt_test!(strictly_negative, StrictlyNegative, "sn", -4.0);
+impl_defmt_test!(strictly_positive_finite, StrictlyPositiveFinite, "spf", 2.5);
+impl_defmt_test!(
+    strictly_negative_finite,
+    StrictlyNegativeFinite,
+    "snf",
+    -0.5
+);
+
+#[test]
+fn test_errors() {
+    assert_format::<InvalidNumber>();
+    assert_format::<NaN>();
+    assert_format::<FromStrError>();
+    assert_format::<FromStrExactError>();
+}
@@ -0,0 +1,161 @@
+use core::num::{NonZeroI64, NonZeroIsize, NonZeroU32, NonZeroUsize};
+use typed_floats::*;
+
+macro_rules! assert_same {
+    ($a:expr, $b:expr, $float:ident) => {{
+        let a: $float = $a.into();
+        let b: $float = $b;
+        assert!(
+            a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+            "{a:?} != {b:?}"
+        );
+    }};
+}
+
+macro_rules! check_int_ops {
+    ($type:ident, $float:ident, $module:ident) => {{
+        let ints: [i64; 9] = [i64::MIN, -7, -2, -1, 0, 1, 3, 1 << 40, i64::MAX];
+
+        for x in $module::get_test_values() {
+            let Ok(a) = $type::<$float>::new(x) else {
+                continue;
+            };
+
+            for n in ints {
+                #[allow(clippy::cast_precision_loss)]
+                let n_float = n as $float;
+
+                assert_same!(a + n, x + n_float, $float);
+                assert_same!(a - n, x - n_float, $float);
+                assert_same!(a * n, x * n_float, $float);
+                assert_same!(a / n, x / n_float, $float);
+                assert_same!(n + a, n_float + x, $float);
+                assert_same!(n - a, n_float - x, $float);
+                assert_same!(n * a, n_float * x, $float);
+                assert_same!(n / a, n_float / x, $float);
+
+                if let Ok(n) = u32::try_from(n) {
+                    #[allow(clippy::cast_lossless)]
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n as $float;
+
+                    assert_same!(a + n, x + n_float, $float);
+                    assert_same!(a * n, x * n_float, $float);
+                    assert_same!(n - a, n_float - x, $float);
+                    assert_same!(n / a, n_float / x, $float);
+                }
+
+                if let Some(n) = NonZeroI64::new(n) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n.get() as $float;
+
+                    assert_same!(a - n, x - n_float, $float);
+                    assert_same!(a / n, x / n_float, $float);
+                    assert_same!(n + a, n_float + x, $float);
+                    assert_same!(n * a, n_float * x, $float);
+                }
+
+                if let Ok(n) = isize::try_from(n) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n as $float;
+
+                    assert_same!(a + n, x + n_float, $float);
+                    assert_same!(a / n, x / n_float, $float);
+                    assert_same!(n - a, n_float - x, $float);
+                    assert_same!(n * a, n_float * x, $float);
+                }
+
+                if let Ok(n) = usize::try_from(n) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n as $float;
+
+                    assert_same!(a - n, x - n_float, $float);
+                    assert_same!(a * n, x * n_float, $float);
+                    assert_same!(n + a, n_float + x, $float);
+                    assert_same!(n / a, n_float / x, $float);
+                }
+
+                if let Some(n) = isize::try_from(n).ok().and_then(NonZeroIsize::new) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n.get() as $float;
+
+                    assert_same!(a + n, x + n_float, $float);
+                    assert_same!(a * n, x * n_float, $float);
+                    assert_same!(n - a, n_float - x, $float);
+                    assert_same!(n / a, n_float / x, $float);
+                }
+
+                if let Some(n) = usize::try_from(n).ok().and_then(NonZeroUsize::new) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n.get() as $float;
+
+                    assert_same!(a - n, x - n_float, $float);
+                    assert_same!(a / n, x / n_float, $float);
+                    assert_same!(n + a, n_float + x, $float);
+                    assert_same!(n * a, n_float * x, $float);
+                }
+
+                if let Some(n) = u32::try_from(n).ok().and_then(NonZeroU32::new) {
+                    #[allow(clippy::cast_lossless)]
+                    #[allow(clippy::cast_precision_loss)]
+                    let n_float = n.get() as $float;
+
+                    assert_same!(a * n, x * n_float, $float);
+                    assert_same!(a / n, x / n_float, $float);
+                    assert_same!(n + a, n_float + x, $float);
+                    assert_same!(n - a, n_float - x, $float);
+                }
+            }
+        }
+    }};
+}
+
+macro_rules! impl_int_ops_test {
+    ($test:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            check_int_ops!($type, f64, tf64);
+            check_int_ops!($type, f32, tf32);
+        }
+    };
+}
+
+impl_int_ops_test!(non_nan, NonNaN);
+impl_int_ops_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_int_ops_test!(non_nan_finite, NonNaNFinite);
+impl_int_ops_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_int_ops_test!(positive, Positive);
+impl_int_ops_test!(negative, Negative);
+impl_int_ops_test!(positive_finite, PositiveFinite);
+impl_int_ops_test!(negative_finite, NegativeFinite);
+impl_int_ops_test!(strictly_positive, StrictlyPositive);
+impl_int_ops_test!(strictly_negative, StrictlyNegative);
+impl_int_ops_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_int_ops_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn output_types() {
+    let x: StrictlyPositiveFinite = 3.0.try_into().expect("valid value");
+    let count = NonZeroU32::new(2).expect("non-zero");
+
+    let mean: Positive = x / count;
+    assert_eq!(mean, 1.5);
+
+    let scaled: Positive = x * count;
+    assert_eq!(scaled, 6.0);
+
+    let mut total: Positive = x.into();
+    total += 4_u32;
+    total *= count;
+    assert_eq!(total, 14.0);
+
+    let product: f64 = tf64::INFINITY * 0_u32;
+    assert!(product.is_nan());
+
+    // The integers are rounded to the nearest float
+    let zero: PositiveFinite = 0.0.try_into().expect("valid value");
+    assert_eq!(zero + u64::MAX, 18_446_744_073_709_551_616.0);
+
+    let zero: PositiveFinite<f32> = 0.0.try_into().expect("valid value");
+    assert_eq!(zero + 16_777_217_u32, 16_777_216.0);
+}
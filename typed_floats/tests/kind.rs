@@ -0,0 +1,119 @@
+use typed_floats::*;
+
+macro_rules! impl_kind_test {
+    ($test:ident, $type:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn of() {
+                assert_eq!(FloatKind::of::<$type<f64>>(), FloatKind::$type);
+                assert_eq!(FloatKind::of::<$type<f32>>(), FloatKind::$type);
+            }
+
+            #[test]
+            fn specifications() {
+                let kind = FloatKind::$type;
+
+                assert_eq!(kind.accept_infinity(), $type::accept_infinity());
+                assert_eq!(kind.accept_zero(), $type::accept_zero());
+                assert_eq!(kind.accept_positive(), $type::accept_positive());
+                assert_eq!(kind.accept_negative(), $type::accept_negative());
+            }
+
+            #[test]
+            fn accepts() {
+                for x in tf64::get_test_values() {
+                    assert_eq!(
+                        FloatKind::$type.accepts(x),
+                        $type::<f64>::new(x).is_ok(),
+                        "{x:?}"
+                    );
+                }
+
+                for x in tf32::get_test_values() {
+                    assert_eq!(
+                        FloatKind::$type.accepts(f64::from(x)),
+                        $type::<f32>::new(x).is_ok(),
+                        "{x:?}"
+                    );
+                }
+            }
+        }
+    };
+}
+
+impl_kind_test!(non_nan, NonNaN);
+impl_kind_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_kind_test!(non_nan_finite, NonNaNFinite);
+impl_kind_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_kind_test!(positive, Positive);
+impl_kind_test!(negative, Negative);
+impl_kind_test!(positive_finite, PositiveFinite);
+impl_kind_test!(negative_finite, NegativeFinite);
+impl_kind_test!(strictly_positive, StrictlyPositive);
+impl_kind_test!(strictly_negative, StrictlyNegative);
+impl_kind_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_kind_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+/// Returns true if all the test values accepted by `a` are accepted by `b`
+fn accepted_values_fit(a: FloatKind, b: FloatKind) -> bool {
+    tf64::get_test_values()
+        .into_iter()
+        .all(|x| !a.accepts(x) || b.accepts(x))
+}
+
+#[test]
+fn test_can_fit_into() {
+    for a in FloatKind::ALL {
+        for b in FloatKind::ALL {
+            assert_eq!(a.can_fit_into(b), accepted_values_fit(a, b), "{a:?} {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_join() {
+    for a in FloatKind::ALL {
+        for b in FloatKind::ALL {
+            let join = a.join(b);
+
+            assert!(a.can_fit_into(join), "{a:?} {b:?}");
+            assert!(b.can_fit_into(join), "{a:?} {b:?}");
+
+            for c in FloatKind::ALL {
+                if a.can_fit_into(c) && b.can_fit_into(c) {
+                    assert!(join.can_fit_into(c), "{a:?} {b:?} {c:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_meet() {
+    for a in FloatKind::ALL {
+        for b in FloatKind::ALL {
+            let accepted_by_both = tf64::get_test_values()
+                .into_iter()
+                .any(|x| a.accepts(x) && b.accepts(x));
+
+            match a.meet(b) {
+                Some(meet) => {
+                    assert!(accepted_by_both, "{a:?} {b:?}");
+                    assert!(meet.can_fit_into(a), "{a:?} {b:?}");
+                    assert!(meet.can_fit_into(b), "{a:?} {b:?}");
+
+                    for c in FloatKind::ALL {
+                        if c.can_fit_into(a) && c.can_fit_into(b) {
+                            assert!(c.can_fit_into(meet), "{a:?} {b:?} {c:?}");
+                        }
+                    }
+                }
+                None => {
+                    assert!(!accepted_by_both, "{a:?} {b:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use typed_floats::*;
+
+fn assert_same(a: f64, b: f64) {
+    assert!(
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+        "{a:?} != {b:?}"
+    );
+}
+
+macro_rules! check_mixed_precision {
+    ($a:ident, $b:ident) => {{
+        for x in tf64::get_test_values() {
+            let Ok(a) = $a::<f64>::new(x) else {
+                continue;
+            };
+
+            for y in tf32::get_test_values() {
+                let Ok(b) = $b::<f32>::new(y) else {
+                    continue;
+                };
+                let b_f64 = $b::<f64>::from(b);
+
+                assert_same(b_f64.get(), f64::from(y));
+
+                assert_eq!(a == b, a == b_f64);
+                assert_eq!(b == a, b_f64 == a);
+                assert_eq!(a.partial_cmp(&b), a.partial_cmp(&b_f64));
+                assert_eq!(b.partial_cmp(&a), b_f64.partial_cmp(&a));
+
+                assert_same((a + b).into(), (a + b_f64).into());
+                assert_same((a - b).into(), (a - b_f64).into());
+                assert_same((a * b).into(), (a * b_f64).into());
+                assert_same((a / b).into(), (a / b_f64).into());
+                assert_same((a % b).into(), (a % b_f64).into());
+
+                assert_same((b + a).into(), (b_f64 + a).into());
+                assert_same((b - a).into(), (b_f64 - a).into());
+                assert_same((b * a).into(), (b_f64 * a).into());
+                assert_same((b / a).into(), (b_f64 / a).into());
+                assert_same((b % a).into(), (b_f64 % a).into());
+            }
+        }
+    }};
+}
+
+macro_rules! check_all_pairs {
+    ([$($a:ident),*], $b:tt) => {
+        $(
+            check_all_pairs!(@one $a, $b);
+        )*
+    };
+    (@one $a:ident, [$($b:ident),*]) => {
+        $(
+            check_mixed_precision!($a, $b);
+        )*
+    };
+}
+
+#[test]
+fn all_pairs() {
+    check_all_pairs!(
+        [
+            NonNaN,
+            NonZeroNonNaN,
+            NonNaNFinite,
+            NonZeroNonNaNFinite,
+            Positive,
+            Negative,
+            PositiveFinite,
+            NegativeFinite,
+            StrictlyPositive,
+            StrictlyNegative,
+            StrictlyPositiveFinite,
+            StrictlyNegativeFinite
+        ],
+        [
+            NonNaN,
+            NonZeroNonNaN,
+            NonNaNFinite,
+            NonZeroNonNaNFinite,
+            Positive,
+            Negative,
+            PositiveFinite,
+            NegativeFinite,
+            StrictlyPositive,
+            StrictlyNegative,
+            StrictlyPositiveFinite,
+            StrictlyNegativeFinite
+        ]
+    );
+}
+
+#[test]
+fn output_types() {
+    let state: StrictlyPositiveFinite = 2.0.try_into().expect("valid value");
+    let sensor: PositiveFinite<f32> = 0.5.try_into().expect("valid value");
+
+    let sum: StrictlyPositive = state + sensor;
+    assert_eq!(sum, 2.5);
+
+    let product: Positive = PositiveFinite::<f64>::from(sensor) * sensor;
+    assert_eq!(product, 0.25);
+
+    let mut total: Positive = state.into();
+    total += sensor;
+    assert_eq!(total, 2.5);
+
+    assert!(state > sensor);
+    assert!(sensor < state);
+    assert_ne!(sensor, state);
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "proptest")]
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use ::proptest::prelude::*;
+use ::proptest::strategy::ValueTree;
+use ::proptest::test_runner::TestRunner;
+use typed_floats::*;
+
+/// Generates values, checking that they are valid while shrinking them.
+/// Returns the fully shrunk values of the trees that were never complicated.
+fn generate<S: Strategy>(strategy: &S, is_valid: impl Fn(&S::Value) -> bool) -> Vec<S::Value> {
+    let mut runner = TestRunner::deterministic();
+    let mut shrunk = Vec::new();
+
+    for i in 0..1_000 {
+        let mut tree = strategy.new_tree(&mut runner).unwrap();
+        assert!(is_valid(&tree.current()), "{:?}", tree.current());
+
+        // Half of the time, simulate a test that passes with some simplified values
+        let complicate = i % 2 == 0;
+
+        let mut steps = 0;
+        while tree.simplify() {
+            assert!(is_valid(&tree.current()), "{:?}", tree.current());
+
+            if complicate && steps % 3 == 0 && tree.complicate() {
+                assert!(is_valid(&tree.current()), "{:?}", tree.current());
+            }
+
+            steps += 1;
+        }
+
+        if !complicate {
+            shrunk.push(tree.current());
+        }
+    }
+
+    shrunk
+}
+
+macro_rules! impl_proptest_test {
+    ($test:ident, $type:ident, $positive_target:expr, $negative_target:expr) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                let shrunk = generate(&any::<$type<f64>>(), |x| $type::<f64>::new(x.get()).is_ok());
+
+                for x in shrunk {
+                    if x.is_sign_positive() {
+                        assert_eq!(x.get().to_bits(), ($positive_target as f64).to_bits());
+                    } else {
+                        assert_eq!(x.get().to_bits(), ($negative_target as f64).to_bits());
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                let shrunk = generate(&any::<$type<f32>>(), |x| $type::<f32>::new(x.get()).is_ok());
+
+                for x in shrunk {
+                    if x.is_sign_positive() {
+                        assert_eq!(x.get().to_bits(), ($positive_target as f32).to_bits());
+                    } else {
+                        assert_eq!(x.get().to_bits(), ($negative_target as f32).to_bits());
+                    }
+                }
+            }
+
+            #[test]
+            fn edges() {
+                let strategy = any::<$type<f64>>()
+                    .with_edge_weight(1.0)
+                    .expect("valid weight");
+                let mut runner = TestRunner::deterministic();
+
+                let generated = (0..1_000)
+                    .map(|_| strategy.new_tree(&mut runner).unwrap().current().get())
+                    .collect::<Vec<f64>>();
+
+                for edge in tf64::get_test_values() {
+                    if $type::<f64>::new(edge).is_ok() {
+                        assert!(
+                            generated.iter().any(|x| x.to_bits() == edge.to_bits()),
+                            "{edge:?} is never generated"
+                        );
+                    }
+                }
+
+                let edges = tf64::get_test_values();
+                for x in generated {
+                    assert!(edges.iter().any(|edge| edge.to_bits() == x.to_bits()));
+                }
+            }
+        }
+    };
+}
+
+// When `0.0` is accepted, all values shrink toward it
+impl_proptest_test!(non_nan, NonNaN, 0.0, 0.0);
+impl_proptest_test!(non_zero_non_nan, NonZeroNonNaN, 1.0, -1.0);
+impl_proptest_test!(non_nan_finite, NonNaNFinite, 0.0, 0.0);
+impl_proptest_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite, 1.0, -1.0);
+impl_proptest_test!(positive, Positive, 0.0, 0.0);
+impl_proptest_test!(negative, Negative, -0.0, -0.0);
+impl_proptest_test!(positive_finite, PositiveFinite, 0.0, 0.0);
+impl_proptest_test!(negative_finite, NegativeFinite, -0.0, -0.0);
+impl_proptest_test!(strictly_positive, StrictlyPositive, 1.0, 1.0);
+impl_proptest_test!(strictly_negative, StrictlyNegative, -1.0, -1.0);
+impl_proptest_test!(strictly_positive_finite, StrictlyPositiveFinite, 1.0, 1.0);
+impl_proptest_test!(strictly_negative_finite, StrictlyNegativeFinite, -1.0, -1.0);
+
+#[test]
+fn test_range() {
+    let strategy =
+        typed_floats::proptest::strictly_positive_finite(10.0f64..20.0).expect("valid range");
+
+    let shrunk = generate(&strategy, |x| (10.0..20.0).contains(&x.get()));
+
+    for x in shrunk {
+        assert_eq!(x, 10.0);
+    }
+
+    let strategy = typed_floats::proptest::positive(..=1e-310f64).expect("valid range");
+
+    let shrunk = generate(&strategy, |x| x.get() <= 1e-310);
+
+    for x in shrunk {
+        assert_eq!(x.get().to_bits(), 0.0f64.to_bits());
+    }
+
+    let strategy = typed_floats::proptest::strictly_positive(..1e-310f64).expect("valid range");
+
+    let shrunk = generate(&strategy, |x| x.get() < 1e-310);
+
+    for x in shrunk {
+        assert_eq!(x, tf64::MIN_SUBNORMAL_POSITIVE);
+    }
+}
+
+#[test]
+fn test_range_without_zero() {
+    let strategy =
+        typed_floats::proptest::non_zero_non_nan_finite(-1e-320f64..=0.0).expect("valid range");
+
+    let shrunk = generate(&strategy, |x| x.get() != 0.0 && x.get() >= -1e-320);
+
+    for x in shrunk {
+        assert_eq!(x, tf64::MIN_SUBNORMAL_NEGATIVE);
+    }
+}
+
+#[test]
+fn test_invalid_strategies() {
+    use typed_floats::proptest::InvalidStrategy;
+
+    assert_eq!(
+        typed_floats::proptest::positive(-2.0f64..-1.0).err(),
+        Some(InvalidStrategy::EmptyRange)
+    );
+    assert_eq!(
+        typed_floats::proptest::positive(2.0f64..1.0).err(),
+        Some(InvalidStrategy::EmptyRange)
+    );
+    assert_eq!(
+        typed_floats::proptest::strictly_positive(-0.0f64..=0.0).err(),
+        Some(InvalidStrategy::EmptyRange)
+    );
+    assert_eq!(
+        typed_floats::proptest::non_nan(f32::NAN..=0.0).err(),
+        Some(InvalidStrategy::NaNBound)
+    );
+    assert_eq!(
+        typed_floats::proptest::non_nan(..f64::NAN).err(),
+        Some(InvalidStrategy::NaNBound)
+    );
+    assert_eq!(
+        typed_floats::proptest::positive::<f64>(..)
+            .expect("valid range")
+            .with_edge_weight(2.0)
+            .err(),
+        Some(InvalidStrategy::InvalidWeight)
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_macro(a in any::<StrictlyPositiveFinite>(), b in any::<StrictlyNegativeFinite<f32>>()) {
+        prop_assert!(a > 0.0);
+        prop_assert!(b < 0.0);
+    }
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "quickcheck")]
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use typed_floats::*;
+
+/// Shrinks `value` like `quickcheck` does when the property always fails
+fn fully_shrink<T: Arbitrary>(value: T, is_valid: impl Fn(&T) -> bool) -> T {
+    let mut value = value;
+
+    while let Some(simpler) = value.shrink().next() {
+        assert!(is_valid(&simpler));
+        value = simpler;
+    }
+
+    value
+}
+
+macro_rules! impl_quickcheck_test {
+    ($test:ident, $type:ident, $positive_target:expr, $negative_target:expr) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                let mut g = Gen::new(100);
+                let values = (0..10_000)
+                    .map(|_| $type::<f64>::arbitrary(&mut g))
+                    .collect::<Vec<_>>();
+
+                for value in &values {
+                    assert!($type::<f64>::new(value.get()).is_ok(), "{value:?}");
+
+                    // All the shrunk values are valid
+                    for simpler in value.shrink() {
+                        assert!($type::<f64>::new(simpler.get()).is_ok(), "{simpler:?}");
+                    }
+                }
+
+                // All the edge values accepted by the type are generated
+                for edge in tf64::get_test_values() {
+                    if $type::<f64>::new(edge).is_ok() {
+                        assert!(
+                            values.iter().any(|x| x.get().to_bits() == edge.to_bits()),
+                            "{edge:?} is never generated"
+                        );
+                    }
+                }
+
+                for value in values {
+                    let target: f64 = if value.is_sign_positive() {
+                        $positive_target
+                    } else {
+                        $negative_target
+                    };
+
+                    let shrunk = fully_shrink(value, |x| $type::<f64>::new(x.get()).is_ok());
+                    assert_eq!(shrunk.get().to_bits(), target.to_bits());
+                }
+            }
+
+            #[test]
+            fn f32() {
+                let mut g = Gen::new(100);
+                let values = (0..10_000)
+                    .map(|_| $type::<f32>::arbitrary(&mut g))
+                    .collect::<Vec<_>>();
+
+                for value in &values {
+                    assert!($type::<f32>::new(value.get()).is_ok(), "{value:?}");
+
+                    // All the shrunk values are valid
+                    for simpler in value.shrink() {
+                        assert!($type::<f32>::new(simpler.get()).is_ok(), "{simpler:?}");
+                    }
+                }
+
+                // All the edge values accepted by the type are generated
+                for edge in tf32::get_test_values() {
+                    if $type::<f32>::new(edge).is_ok() {
+                        assert!(
+                            values.iter().any(|x| x.get().to_bits() == edge.to_bits()),
+                            "{edge:?} is never generated"
+                        );
+                    }
+                }
+
+                for value in values {
+                    let target: f32 = if value.is_sign_positive() {
+                        $positive_target
+                    } else {
+                        $negative_target
+                    };
+
+                    let shrunk = fully_shrink(value, |x| $type::<f32>::new(x.get()).is_ok());
+                    assert_eq!(shrunk.get().to_bits(), target.to_bits());
+                }
+            }
+        }
+    };
+}
+
+impl_quickcheck_test!(non_nan, NonNaN, 0.0, -0.0);
+impl_quickcheck_test!(non_zero_non_nan, NonZeroNonNaN, 1.0, -1.0);
+impl_quickcheck_test!(non_nan_finite, NonNaNFinite, 0.0, -0.0);
+impl_quickcheck_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite, 1.0, -1.0);
+impl_quickcheck_test!(positive, Positive, 0.0, 0.0);
+impl_quickcheck_test!(negative, Negative, -0.0, -0.0);
+impl_quickcheck_test!(positive_finite, PositiveFinite, 0.0, 0.0);
+impl_quickcheck_test!(negative_finite, NegativeFinite, -0.0, -0.0);
+impl_quickcheck_test!(strictly_positive, StrictlyPositive, 1.0, 1.0);
+impl_quickcheck_test!(strictly_negative, StrictlyNegative, -1.0, -1.0);
+impl_quickcheck_test!(strictly_positive_finite, StrictlyPositiveFinite, 1.0, 1.0);
+impl_quickcheck_test!(strictly_negative_finite, StrictlyNegativeFinite, -1.0, -1.0);
+
+#[test]
+fn test_shrink_toward_target() {
+    let x = StrictlyNegativeFinite::<f64>::new(-8.0).unwrap();
+    let shrunk = x.shrink().map(|x| x.get()).collect::<Vec<f64>>();
+
+    assert_eq!(shrunk.first(), Some(&-1.0));
+    assert!(shrunk
+        .iter()
+        .all(|&y| (-8.0..=-1.0).contains(&y) && y != -8.0));
+
+    let x = StrictlyPositive::<f64>::new(f64::INFINITY).unwrap();
+    let shrunk = x.shrink().map(|x| x.get()).collect::<Vec<f64>>();
+
+    assert_eq!(shrunk.first(), Some(&1.0));
+    assert_eq!(shrunk.get(1), Some(&f64::MAX));
+    assert!(shrunk.iter().all(|y| y.is_finite()));
+
+    let x = Positive::<f64>::new(0.0).unwrap();
+    assert_eq!(x.shrink().count(), 0);
+}
+
+quickcheck! {
+    fn test_macro(a: StrictlyPositiveFinite, b: StrictlyNegativeFinite<f32>) -> bool {
+        a > 0.0 && b < 0.0
+    }
+}
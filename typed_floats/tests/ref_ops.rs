@@ -0,0 +1,69 @@
+use core::num::NonZeroU32;
+use core::ops::{Add, Div};
+use typed_floats::*;
+
+// Written like the generic code of linear-algebra crates, only requiring operators on references
+fn sum<T: Default>(values: &[T]) -> T
+where
+    for<'a> &'a T: Add<&'a T, Output = T>,
+{
+    values.iter().fold(T::default(), |acc, x| &acc + x)
+}
+
+#[test]
+fn generic_code_on_references() {
+    let a: [Positive; 3] = [1.0, 2.0, 3.0].map(|x| x.try_into().expect("valid value"));
+    let b: [Negative<f32>; 2] = [-4.0, -5.0].map(|x| x.try_into().expect("valid value"));
+
+    assert_eq!(sum(&a), 6.0);
+    assert_eq!(sum(&b), -9.0);
+}
+
+#[test]
+fn assign_with_reference() {
+    let mut x: Positive<f32> = 1.5.try_into().expect("valid value");
+    let y: StrictlyPositiveFinite<f32> = 2.0.try_into().expect("valid value");
+
+    x += &y;
+    x *= &y;
+
+    assert_eq!(x, 7.0);
+    assert_eq!(Div::div(&x, &y), 3.5);
+    assert_eq!(Max::max(&x, y), 7.0);
+}
+
+#[test]
+// The references are the point of the test, and the results are exact
+#[allow(clippy::op_ref, clippy::float_cmp)]
+fn mixed_precision_on_references() {
+    let a: Positive = 1.5.try_into().expect("valid value");
+    let b: StrictlyPositiveFinite<f32> = 2.0.try_into().expect("valid value");
+
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &b, a * b);
+    assert_eq!(&b / &a, b / a);
+
+    let mut c = a;
+    c += &b;
+    assert_eq!(c, 3.5);
+}
+
+#[test]
+// The references are the point of the test, and the results are exact
+#[allow(clippy::op_ref, clippy::float_cmp)]
+fn integers_on_references() {
+    let x: Positive = 1.5.try_into().expect("valid value");
+    let n = NonZeroU32::new(2).expect("valid value");
+
+    assert_eq!(&x * 3u32, 4.5);
+    assert_eq!(x / &n, 0.75);
+    assert_eq!(&x + &n, 3.5);
+    assert_eq!(&2i64 - &x, 0.5);
+    assert_eq!(&n * x, 3.0);
+
+    let mut y = x;
+    y += &3u8;
+    y *= &n;
+    assert_eq!(y, 9.0);
+}
@@ -0,0 +1,250 @@
+use typed_floats::*;
+
+macro_rules! impl_refine_test {
+    ($test:ident, $method:ident, $from:ident, $to:ident, $is_rejected:expr, $invalid:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                for x in tf64::get_test_values() {
+                    let Ok(value) = $from::<f64>::new(x) else {
+                        continue;
+                    };
+
+                    match value.$method() {
+                        Ok(refined) => {
+                            let refined: $to<f64> = refined;
+                            assert!(!$is_rejected(x), "{x:?}");
+                            assert_eq!(refined.get().to_bits(), x.to_bits());
+                        }
+                        Err(rejected) => {
+                            assert!($is_rejected(x), "{x:?}");
+                            assert_eq!(InvalidNumber::from(rejected), InvalidNumber::$invalid);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                for x in tf32::get_test_values() {
+                    let Ok(value) = $from::<f32>::new(x) else {
+                        continue;
+                    };
+
+                    match value.$method() {
+                        Ok(refined) => {
+                            let refined: $to<f32> = refined;
+                            assert!(!$is_rejected(f64::from(x)), "{x:?}");
+                            assert_eq!(refined.get().to_bits(), x.to_bits());
+                        }
+                        Err(rejected) => {
+                            assert!($is_rejected(f64::from(x)), "{x:?}");
+                            assert_eq!(InvalidNumber::from(rejected), InvalidNumber::$invalid);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+fn is_zero(x: f64) -> bool {
+    x == 0.0
+}
+
+fn is_infinite(x: f64) -> bool {
+    x.is_infinite()
+}
+
+impl_refine_test!(
+    non_nan_zero,
+    refine_zero,
+    NonNaN,
+    NonZeroNonNaN,
+    is_zero,
+    Zero
+);
+impl_refine_test!(
+    non_nan_finite_zero,
+    refine_zero,
+    NonNaNFinite,
+    NonZeroNonNaNFinite,
+    is_zero,
+    Zero
+);
+impl_refine_test!(
+    positive_zero,
+    refine_zero,
+    Positive,
+    StrictlyPositive,
+    is_zero,
+    Zero
+);
+impl_refine_test!(
+    negative_zero,
+    refine_zero,
+    Negative,
+    StrictlyNegative,
+    is_zero,
+    Zero
+);
+impl_refine_test!(
+    positive_finite_zero,
+    refine_zero,
+    PositiveFinite,
+    StrictlyPositiveFinite,
+    is_zero,
+    Zero
+);
+impl_refine_test!(
+    negative_finite_zero,
+    refine_zero,
+    NegativeFinite,
+    StrictlyNegativeFinite,
+    is_zero,
+    Zero
+);
+
+impl_refine_test!(
+    non_nan_finite,
+    refine_finite,
+    NonNaN,
+    NonNaNFinite,
+    is_infinite,
+    Infinite
+);
+impl_refine_test!(
+    non_zero_non_nan_finite,
+    refine_finite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    is_infinite,
+    Infinite
+);
+impl_refine_test!(
+    positive_finite,
+    refine_finite,
+    Positive,
+    PositiveFinite,
+    is_infinite,
+    Infinite
+);
+impl_refine_test!(
+    negative_finite,
+    refine_finite,
+    Negative,
+    NegativeFinite,
+    is_infinite,
+    Infinite
+);
+impl_refine_test!(
+    strictly_positive_finite,
+    refine_finite,
+    StrictlyPositive,
+    StrictlyPositiveFinite,
+    is_infinite,
+    Infinite
+);
+impl_refine_test!(
+    strictly_negative_finite,
+    refine_finite,
+    StrictlyNegative,
+    StrictlyNegativeFinite,
+    is_infinite,
+    Infinite
+);
+
+macro_rules! impl_split_sign_test {
+    ($test:ident, $from:ident, $positive:ident, $negative:ident) => {
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn f64() {
+                for x in tf64::get_test_values() {
+                    let Ok(value) = $from::<f64>::new(x) else {
+                        continue;
+                    };
+
+                    match value.split_sign() {
+                        Signed::Positive(positive) => {
+                            let positive: $positive<f64> = positive;
+                            assert!(x.is_sign_positive(), "{x:?}");
+                            assert_eq!(positive.get().to_bits(), x.to_bits());
+                        }
+                        Signed::Negative(negative) => {
+                            let negative: $negative<f64> = negative;
+                            assert!(x.is_sign_negative(), "{x:?}");
+                            assert_eq!(negative.get().to_bits(), x.to_bits());
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn f32() {
+                for x in tf32::get_test_values() {
+                    let Ok(value) = $from::<f32>::new(x) else {
+                        continue;
+                    };
+
+                    match value.split_sign() {
+                        Signed::Positive(positive) => {
+                            let positive: $positive<f32> = positive;
+                            assert!(x.is_sign_positive(), "{x:?}");
+                            assert_eq!(positive.get().to_bits(), x.to_bits());
+                        }
+                        Signed::Negative(negative) => {
+                            let negative: $negative<f32> = negative;
+                            assert!(x.is_sign_negative(), "{x:?}");
+                            assert_eq!(negative.get().to_bits(), x.to_bits());
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_split_sign_test!(non_nan_sign, NonNaN, Positive, Negative);
+impl_split_sign_test!(
+    non_zero_non_nan_sign,
+    NonZeroNonNaN,
+    StrictlyPositive,
+    StrictlyNegative
+);
+impl_split_sign_test!(
+    non_nan_finite_sign,
+    NonNaNFinite,
+    PositiveFinite,
+    NegativeFinite
+);
+impl_split_sign_test!(
+    non_zero_non_nan_finite_sign,
+    NonZeroNonNaNFinite,
+    StrictlyPositiveFinite,
+    StrictlyNegativeFinite
+);
+
+#[test]
+fn test_rejected_values() {
+    let zero: Positive = tf!(0.0).into();
+    assert_eq!(zero.refine_zero(), Err(PositiveZero));
+
+    let zero: Negative = tf!(-0.0).into();
+    assert_eq!(zero.refine_zero(), Err(NegativeZero));
+
+    let zero: NonNaN = tf!(-0.0).into();
+    assert_eq!(zero.refine_zero(), Err(Signed::Negative(NegativeZero)));
+
+    let inf: StrictlyPositive = tf!(INFINITY);
+    assert_eq!(inf.refine_finite(), Err(PositiveInfinity));
+
+    let inf: StrictlyNegative = tf!(NEG_INFINITY);
+    assert_eq!(inf.refine_finite(), Err(NegativeInfinity));
+
+    let inf: NonZeroNonNaN = tf!(INFINITY).into();
+    assert_eq!(inf.refine_finite(), Err(Signed::Positive(PositiveInfinity)));
+}
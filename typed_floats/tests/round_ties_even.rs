@@ -0,0 +1,7 @@
+#![cfg(feature = "std")]
+
+#[rustversion::since(1.77)]
+use typed_floats::*;
+
+#[rustversion::since(1.77)]
+typed_floats_macros::generate_tests_self!(round_ties_even);
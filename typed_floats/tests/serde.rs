@@ -46,3 +46,68 @@ fn test_serde_struct() {
 
     assert_eq!(a_json, map);
 }
+
+#[test]
+fn test_serde_f32_strict() {
+    #[derive(serde::Deserialize, serde::Serialize, Debug)]
+    struct A {
+        #[serde(with = "typed_floats::serde_f32::strict")]
+        a: Positive<f32>,
+    }
+
+    // The default deserialization rounds before checking
+    let a: Positive<f32> = serde_json::from_str("3.4e39").unwrap();
+    assert_eq!(a, f32::INFINITY);
+
+    let a: A = serde_json::from_str(r#"{ "a": 0.1 }"#).unwrap();
+    assert_eq!(a.a, 0.1f32);
+    assert_eq!(serde_json::to_string(&a).unwrap(), r#"{"a":0.1}"#);
+
+    let a: A = serde_json::from_str(r#"{ "a": 3.4e38 }"#).unwrap();
+    assert_eq!(a.a, 3.4e38f32);
+
+    let a: A = serde_json::from_str(r#"{ "a": 0.0 }"#).unwrap();
+    assert_eq!(a.a, 0.0f32);
+
+    // Subnormals are kept
+    let a: A = serde_json::from_str(r#"{ "a": 1e-45 }"#).unwrap();
+    assert_eq!(a.a, 1e-45f32);
+
+    // `serde_json` may not parse the closest `f64`, so only the end of the messages is checked
+    let error = serde_json::from_str::<A>(r#"{ "a": 3.4e39 }"#).unwrap_err();
+    assert!(error.to_string().contains("overflows to infinity in f32"));
+
+    let error = serde_json::from_str::<A>(r#"{ "a": 1e-50 }"#).unwrap_err();
+    assert!(error.to_string().contains("underflows to zero in f32"));
+
+    let error = serde_json::from_str::<A>(r#"{ "a": -1.0 }"#).unwrap_err();
+    assert!(error.to_string().starts_with("Number is negative"));
+}
+
+#[test]
+fn test_serde_f32_exact() {
+    #[derive(serde::Deserialize, Debug)]
+    struct A {
+        #[serde(with = "typed_floats::serde_f32::exact")]
+        a: NonNaNFinite<f32>,
+    }
+
+    for x in tf32::get_test_values() {
+        if let Ok(typed) = NonNaNFinite::<f32>::new(x) {
+            let json = format!(r#"{{ "a": {} }}"#, f64::from(x));
+            let a: A = serde_json::from_str(&json).unwrap();
+            assert_eq!(a.a.get().to_bits(), typed.get().to_bits());
+        }
+    }
+
+    let error = serde_json::from_str::<A>(r#"{ "a": 0.1 }"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("0.1 is not exactly representable in f32"));
+
+    let error = serde_json::from_str::<A>(r#"{ "a": 3.4e39 }"#).unwrap_err();
+    assert!(error.to_string().contains("overflows to infinity in f32"));
+
+    let error = serde_json::from_str::<A>(r#"{ "a": 1e-50 }"#).unwrap_err();
+    assert!(error.to_string().contains("underflows to zero in f32"));
+}
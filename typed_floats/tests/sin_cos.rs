@@ -0,0 +1,51 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+macro_rules! impl_sin_cos_test {
+    ($test:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            for x in tf64::get_test_values() {
+                if let Ok(a) = $type::<f64>::new(x) {
+                    let (sin, cos) = a.sin_cos();
+
+                    assert_eq!(f64::from(sin).to_bits(), f64::from(a.sin()).to_bits());
+                    assert_eq!(f64::from(cos).to_bits(), f64::from(a.cos()).to_bits());
+                }
+            }
+
+            for x in tf32::get_test_values() {
+                if let Ok(a) = $type::<f32>::new(x) {
+                    let (sin, cos) = a.sin_cos();
+
+                    assert_eq!(f32::from(sin).to_bits(), f32::from(a.sin()).to_bits());
+                    assert_eq!(f32::from(cos).to_bits(), f32::from(a.cos()).to_bits());
+                }
+            }
+        }
+    };
+}
+
+impl_sin_cos_test!(non_nan, NonNaN);
+impl_sin_cos_test!(non_zero_non_nan, NonZeroNonNaN);
+impl_sin_cos_test!(non_nan_finite, NonNaNFinite);
+impl_sin_cos_test!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_sin_cos_test!(positive, Positive);
+impl_sin_cos_test!(negative, Negative);
+impl_sin_cos_test!(positive_finite, PositiveFinite);
+impl_sin_cos_test!(negative_finite, NegativeFinite);
+impl_sin_cos_test!(strictly_positive, StrictlyPositive);
+impl_sin_cos_test!(strictly_negative, StrictlyNegative);
+impl_sin_cos_test!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_sin_cos_test!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn finite_output() {
+    let a: NonNaNFinite = 1.0.try_into().expect("valid value");
+
+    let (sin, cos): (NonNaNFinite, NonNaNFinite) = a.sin_cos();
+
+    assert_eq!(sin, a.sin());
+    assert_eq!(cos, a.cos());
+}
@@ -0,0 +1,80 @@
+use typed_floats::*;
+
+#[test]
+fn test_kinds() {
+    let x: StrictlyPositiveFinite = tf!(2.0);
+    assert_eq!(x.get().to_bits(), 2.0f64.to_bits());
+
+    let x: StrictlyNegativeFinite = tf!(-2.5);
+    assert_eq!(x.get().to_bits(), (-2.5f64).to_bits());
+
+    let x: PositiveFinite = tf!(0.0);
+    assert_eq!(x.get().to_bits(), 0.0f64.to_bits());
+
+    let x: NegativeFinite = tf!(-0.0);
+    assert_eq!(x.get().to_bits(), (-0.0f64).to_bits());
+
+    let x: StrictlyPositive = tf!(INFINITY);
+    assert_eq!(x.get().to_bits(), f64::INFINITY.to_bits());
+
+    let x: StrictlyNegative = tf!(NEG_INFINITY);
+    assert_eq!(x.get().to_bits(), f64::NEG_INFINITY.to_bits());
+
+    let x: StrictlyNegative = tf!(-f64::INFINITY);
+    assert_eq!(x.get().to_bits(), f64::NEG_INFINITY.to_bits());
+
+    let x: StrictlyPositive = tf!(-f64::NEG_INFINITY);
+    assert_eq!(x.get().to_bits(), f64::INFINITY.to_bits());
+}
+
+#[test]
+fn test_integers() {
+    let x: StrictlyPositiveFinite = tf!(3);
+    assert_eq!(x.get().to_bits(), 3.0f64.to_bits());
+
+    let x: PositiveFinite = tf!(0);
+    assert_eq!(x.get().to_bits(), 0.0f64.to_bits());
+
+    let x: NegativeFinite = tf!(-0);
+    assert_eq!(x.get().to_bits(), (-0.0f64).to_bits());
+
+    let x: StrictlyNegativeFinite<f32> = tf!(f32, -0x10);
+    assert_eq!(x.get().to_bits(), (-16.0f32).to_bits());
+}
+
+#[test]
+fn test_precision() {
+    let x: StrictlyPositiveFinite<f32> = tf!(f32, 0.1);
+    assert_eq!(x.get().to_bits(), 0.1f32.to_bits());
+
+    let x: StrictlyPositiveFinite<f32> = tf!(0.1f32);
+    assert_eq!(x.get().to_bits(), 0.1f32.to_bits());
+
+    let x: StrictlyPositiveFinite<f64> = tf!(f64, 0.1f64);
+    assert_eq!(x.get().to_bits(), 0.1f64.to_bits());
+
+    let x: StrictlyNegative<f32> = tf!(f32::NEG_INFINITY);
+    assert_eq!(x.get().to_bits(), f32::NEG_INFINITY.to_bits());
+
+    // The value is rounded like the primitive literal
+    let x: StrictlyPositiveFinite = tf!(1.000_000_000_000_000_1);
+    assert_eq!(x.get().to_bits(), 1.0f64.to_bits());
+
+    let x: StrictlyPositiveFinite = tf!(1e-320);
+    assert_eq!(x.get().to_bits(), 1e-320f64.to_bits());
+
+    let x: StrictlyPositiveFinite = tf!(1.7976931348623157e308);
+    assert_eq!(x.get().to_bits(), f64::MAX.to_bits());
+
+    let x: StrictlyPositiveFinite<f32> = tf!(f32, 3.4028235e38);
+    assert_eq!(x.get().to_bits(), f32::MAX.to_bits());
+}
+
+#[test]
+fn test_const() {
+    const TWO: StrictlyPositiveFinite = tf!(2.0);
+    const MINUS_ZERO: NegativeFinite<f32> = tf!(f32, -0.0);
+
+    assert_eq!(TWO, 2.0);
+    assert!(MINUS_ZERO.is_negative_zero());
+}
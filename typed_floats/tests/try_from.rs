@@ -0,0 +1,155 @@
+use typed_floats::*;
+
+/// Checks that the conversion fails like `new` for the same reason
+macro_rules! check_pair {
+    ($from:ident, $to:ident) => {
+        for x in tf64::get_test_values() {
+            if let Ok(value) = $from::<f64>::new(x) {
+                let converted = $to::<f64>::try_from(value).map_err(InvalidNumber::from);
+                assert_eq!(converted, $to::<f64>::new(x), "{x:?}");
+            }
+        }
+
+        for x in tf32::get_test_values() {
+            if let Ok(value) = $from::<f32>::new(x) {
+                let converted = $to::<f32>::try_from(value).map_err(InvalidNumber::from);
+                assert_eq!(converted, $to::<f32>::new(x), "{x:?}");
+            }
+        }
+    };
+}
+
+#[test]
+fn test_same_errors_as_new() {
+    check_pair!(NonNaN, NonZeroNonNaN);
+    check_pair!(NonNaN, NonNaNFinite);
+    check_pair!(NonNaN, NonZeroNonNaNFinite);
+    check_pair!(NonNaN, Positive);
+    check_pair!(NonNaN, Negative);
+    check_pair!(NonNaN, PositiveFinite);
+    check_pair!(NonNaN, NegativeFinite);
+    check_pair!(NonNaN, StrictlyPositive);
+    check_pair!(NonNaN, StrictlyNegative);
+    check_pair!(NonNaN, StrictlyPositiveFinite);
+    check_pair!(NonNaN, StrictlyNegativeFinite);
+    check_pair!(NonZeroNonNaN, NonNaNFinite);
+    check_pair!(NonZeroNonNaN, NonZeroNonNaNFinite);
+    check_pair!(NonZeroNonNaN, Positive);
+    check_pair!(NonZeroNonNaN, Negative);
+    check_pair!(NonZeroNonNaN, PositiveFinite);
+    check_pair!(NonZeroNonNaN, NegativeFinite);
+    check_pair!(NonZeroNonNaN, StrictlyPositive);
+    check_pair!(NonZeroNonNaN, StrictlyNegative);
+    check_pair!(NonZeroNonNaN, StrictlyPositiveFinite);
+    check_pair!(NonZeroNonNaN, StrictlyNegativeFinite);
+    check_pair!(NonNaNFinite, NonZeroNonNaN);
+    check_pair!(NonNaNFinite, NonZeroNonNaNFinite);
+    check_pair!(NonNaNFinite, Positive);
+    check_pair!(NonNaNFinite, Negative);
+    check_pair!(NonNaNFinite, PositiveFinite);
+    check_pair!(NonNaNFinite, NegativeFinite);
+    check_pair!(NonNaNFinite, StrictlyPositive);
+    check_pair!(NonNaNFinite, StrictlyNegative);
+    check_pair!(NonNaNFinite, StrictlyPositiveFinite);
+    check_pair!(NonNaNFinite, StrictlyNegativeFinite);
+    check_pair!(NonZeroNonNaNFinite, Positive);
+    check_pair!(NonZeroNonNaNFinite, Negative);
+    check_pair!(NonZeroNonNaNFinite, PositiveFinite);
+    check_pair!(NonZeroNonNaNFinite, NegativeFinite);
+    check_pair!(NonZeroNonNaNFinite, StrictlyPositive);
+    check_pair!(NonZeroNonNaNFinite, StrictlyNegative);
+    check_pair!(NonZeroNonNaNFinite, StrictlyPositiveFinite);
+    check_pair!(NonZeroNonNaNFinite, StrictlyNegativeFinite);
+    check_pair!(Positive, NonZeroNonNaN);
+    check_pair!(Positive, NonNaNFinite);
+    check_pair!(Positive, NonZeroNonNaNFinite);
+    check_pair!(Positive, Negative);
+    check_pair!(Positive, PositiveFinite);
+    check_pair!(Positive, NegativeFinite);
+    check_pair!(Positive, StrictlyPositive);
+    check_pair!(Positive, StrictlyNegative);
+    check_pair!(Positive, StrictlyPositiveFinite);
+    check_pair!(Positive, StrictlyNegativeFinite);
+    check_pair!(Negative, NonZeroNonNaN);
+    check_pair!(Negative, NonNaNFinite);
+    check_pair!(Negative, NonZeroNonNaNFinite);
+    check_pair!(Negative, Positive);
+    check_pair!(Negative, PositiveFinite);
+    check_pair!(Negative, NegativeFinite);
+    check_pair!(Negative, StrictlyPositive);
+    check_pair!(Negative, StrictlyNegative);
+    check_pair!(Negative, StrictlyPositiveFinite);
+    check_pair!(Negative, StrictlyNegativeFinite);
+    check_pair!(PositiveFinite, NonZeroNonNaN);
+    check_pair!(PositiveFinite, NonZeroNonNaNFinite);
+    check_pair!(PositiveFinite, Negative);
+    check_pair!(PositiveFinite, NegativeFinite);
+    check_pair!(PositiveFinite, StrictlyPositive);
+    check_pair!(PositiveFinite, StrictlyNegative);
+    check_pair!(PositiveFinite, StrictlyPositiveFinite);
+    check_pair!(PositiveFinite, StrictlyNegativeFinite);
+    check_pair!(NegativeFinite, NonZeroNonNaN);
+    check_pair!(NegativeFinite, NonZeroNonNaNFinite);
+    check_pair!(NegativeFinite, Positive);
+    check_pair!(NegativeFinite, PositiveFinite);
+    check_pair!(NegativeFinite, StrictlyPositive);
+    check_pair!(NegativeFinite, StrictlyNegative);
+    check_pair!(NegativeFinite, StrictlyPositiveFinite);
+    check_pair!(NegativeFinite, StrictlyNegativeFinite);
+    check_pair!(StrictlyPositive, NonNaNFinite);
+    check_pair!(StrictlyPositive, NonZeroNonNaNFinite);
+    check_pair!(StrictlyPositive, Negative);
+    check_pair!(StrictlyPositive, PositiveFinite);
+    check_pair!(StrictlyPositive, NegativeFinite);
+    check_pair!(StrictlyPositive, StrictlyNegative);
+    check_pair!(StrictlyPositive, StrictlyPositiveFinite);
+    check_pair!(StrictlyPositive, StrictlyNegativeFinite);
+    check_pair!(StrictlyNegative, NonNaNFinite);
+    check_pair!(StrictlyNegative, NonZeroNonNaNFinite);
+    check_pair!(StrictlyNegative, Positive);
+    check_pair!(StrictlyNegative, PositiveFinite);
+    check_pair!(StrictlyNegative, NegativeFinite);
+    check_pair!(StrictlyNegative, StrictlyPositive);
+    check_pair!(StrictlyNegative, StrictlyPositiveFinite);
+    check_pair!(StrictlyNegative, StrictlyNegativeFinite);
+    check_pair!(StrictlyPositiveFinite, Negative);
+    check_pair!(StrictlyPositiveFinite, NegativeFinite);
+    check_pair!(StrictlyPositiveFinite, StrictlyNegative);
+    check_pair!(StrictlyPositiveFinite, StrictlyNegativeFinite);
+    check_pair!(StrictlyNegativeFinite, Positive);
+    check_pair!(StrictlyNegativeFinite, PositiveFinite);
+    check_pair!(StrictlyNegativeFinite, StrictlyPositive);
+    check_pair!(StrictlyNegativeFinite, StrictlyPositiveFinite);
+}
+
+#[test]
+fn test_possible_reasons() {
+    let zero: PositiveFinite = tf!(0.0);
+    let error: Rejected<Impossible, Impossible, Impossible, Possible> =
+        StrictlyPositiveFinite::try_from(zero).expect_err("invalid value");
+    assert_eq!(error, Rejected::Zero(Possible));
+
+    let minus_zero: NonNaN = tf!(-0.0).into();
+    let error: Rejected<Impossible, Possible, Impossible, Possible> =
+        StrictlyPositive::try_from(minus_zero).expect_err("invalid value");
+    assert_eq!(error, Rejected::Negative(Possible));
+    assert_eq!(error.to_string(), InvalidNumber::Negative.to_string());
+
+    let inf: Positive = tf!(INFINITY).into();
+    let error: Rejected<Possible, Impossible, Impossible, Possible> =
+        StrictlyPositiveFinite::try_from(inf).expect_err("invalid value");
+    assert_eq!(error, Rejected::Infinite(Possible));
+}
+
+#[rustversion::since(1.82)]
+#[test]
+fn test_exhaustive_match() {
+    let zero: PositiveFinite = tf!(0.0);
+
+    let is_zero = match StrictlyPositiveFinite::try_from(zero) {
+        Ok(_) => false,
+        Err(Rejected::Zero(Possible)) => true,
+    };
+
+    assert!(is_zero);
+}
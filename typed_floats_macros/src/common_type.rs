@@ -0,0 +1,43 @@
+use quote::quote;
+
+use crate::types::{
+    return_type_definition, FloatDefinition, FloatSpecifications, ReturnTypeDefinition,
+    ReturnTypeSpecification,
+};
+
+/// Generates the `CommonType` implementation, with the strictest type accepting the values of both types as output.
+pub(crate) fn impl_common_type(
+    float_a: &FloatDefinition,
+    float_b: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let a = &float_a.s;
+    let b = &float_b.s;
+
+    let union = ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+        accept_inf: a.accept_inf || b.accept_inf,
+        accept_zero: a.accept_zero || b.accept_zero,
+        accept_positive: a.accept_positive || b.accept_positive,
+        accept_negative: a.accept_negative || b.accept_negative,
+    });
+
+    let ReturnTypeDefinition::FloatDefinition(output) = return_type_definition(&union, floats)
+    else {
+        unreachable!("The union of two types is always a type");
+    };
+
+    let a_full_type = float_a.full_type_ident();
+    let b_full_type = float_b.full_type_ident();
+    let output_full_type = output.full_type_ident();
+
+    quote! {
+        impl crate::CommonType<#b_full_type> for #a_full_type {
+            type Output = #output_full_type;
+
+            #[inline]
+            fn into_common(self) -> Self::Output {
+                self.into()
+            }
+        }
+    }
+}
@@ -230,6 +230,29 @@ pub fn generate_tests_self_rhs(float_type: &'static str, filter: &str) -> proc_m
                     }
 
                     #vals.push(f);
+
+                    // Check that the implementations on references give the same result
+                    {
+                        let res_ref = {
+                            let num_a = &num_a;
+                            let num_b = &num_b;
+                            #test
+                        };
+                        let res_ref_lhs = {
+                            let num_a = &num_a;
+                            #test
+                        };
+                        let res_ref_rhs = {
+                            let num_b = &num_b;
+                            #test
+                        };
+
+                        if res == res {
+                            assert_eq!(res, res_ref, "{} on references", #op_name);
+                            assert_eq!(res, res_ref_lhs, "{} on a reference", #op_name);
+                            assert_eq!(res, res_ref_rhs, "{} with a reference", #op_name);
+                        }
+                    }
                 });
 
                 if op.op_is_commutative {
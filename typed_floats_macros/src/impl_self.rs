@@ -2,6 +2,9 @@ use quote::quote;
 
 use crate::types::{FloatSpecifications, Op, OpBuilder, ReturnTypeSpecification};
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::types::{output_name, FloatDefinition, ReturnTypeDefinition};
+
 pub fn get_impl_self() -> Vec<Op> {
     let mut ops = vec![
         OpBuilder::new("neg")
@@ -69,6 +72,7 @@ pub fn get_impl_self() -> Vec<Op> {
                 ReturnTypeSpecification::FloatSpecifications(output_spec)
             }))
             .const_since("1.85")
+            .const_counterpart("1.85", None)
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
         OpBuilder::new("ceil")
@@ -1116,6 +1120,8 @@ pub fn get_impl_self() -> Vec<Op> {
                 })
             }))
             .const_since("1.85")
+            // `f64::recip` is only `const` since 1.85, but it is the same as `1.0 / x`
+            .const_counterpart("1.83", Some(Box::new(|_| quote! { 1.0 / self.get() })))
             .skip_check_return_type_strictness()
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
@@ -1202,5 +1208,101 @@ pub fn get_impl_self() -> Vec<Op> {
         ops.push(next_down.build());
     }
 
+    // Not available in `num_traits`
+    #[cfg(feature = "std")]
+    if rustversion::cfg!(since(1.77)) {
+        let round_ties_even = OpBuilder::new("round_ties_even")
+            .description(quote! {
+                /// Returns the nearest integer to a number. Rounds half-way cases to the number
+                /// with an even least significant digit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use typed_floats::*;
+                /// let a: NonNaN = 3.5.try_into().unwrap();
+                /// let b: NonNaN = (-2.5).try_into().unwrap();
+                ///
+                /// assert_eq!(a.round_ties_even(), 4.0);
+                /// assert_eq!(b.round_ties_even(), -2.0);
+                ///
+                /// assert_eq!(tf64::INFINITY.round_ties_even(), tf64::INFINITY);
+                /// ```
+                ///
+                /// See [`f64::round_ties_even()`] for more details.
+            })
+            .result(Box::new(|float| {
+                let mut output_spec = float.s.clone();
+
+                output_spec.accept_zero = true;
+
+                ReturnTypeSpecification::FloatSpecifications(output_spec)
+            }));
+
+        ops.push(round_ties_even.build());
+    }
+
     ops
 }
+
+/// Generates `sin_cos`, with the return types of `sin` and `cos`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn get_impl_sin_cos(
+    ops: &[Op],
+    float: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let result = |key: &str| {
+        ops.iter()
+            .find(|op| op.key == key)
+            .expect("`sin` and `cos` are defined")
+            .get_result(float, floats)
+    };
+
+    let wrap = |output: &ReturnTypeDefinition, value: proc_macro2::TokenStream| match output {
+        ReturnTypeDefinition::FloatDefinition(d) => {
+            let output_call = d.call_tokens();
+
+            quote! { unsafe { #output_call::new_unchecked(#value) } }
+        }
+        ReturnTypeDefinition::NativeFloat => value,
+    };
+
+    let float_full_type = float.full_type_ident();
+    let float_type = float.float_type_ident();
+
+    let sin = result("sin");
+    let cos = result("cos");
+    let sin_name = output_name(&sin, &float_type);
+    let cos_name = output_name(&cos, &float_type);
+    let sin_value = wrap(&sin, quote! { sin });
+    let cos_value = wrap(&cos, quote! { cos });
+
+    quote! {
+        impl #float_full_type {
+            /// Simultaneously computes the sine and cosine of the number, `x`.
+            /// Returns `(sin(x), cos(x))`, with the same types as [`Self::sin()`] and [`Self::cos()`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let a: NonNaNFinite = 0.0.try_into().unwrap();
+            ///
+            /// let (sin, cos) = a.sin_cos();
+            ///
+            /// assert_eq!(sin, 0.0);
+            /// assert_eq!(cos, 1.0);
+            /// ```
+            ///
+            /// See [`f64::sin_cos()`] for more details.
+            #[inline]
+            #[must_use]
+            pub fn sin_cos(self) -> (#sin_name, #cos_name) {
+                let (sin, cos) = self.get().sin_cos();
+
+                (#sin_value, #cos_value)
+            }
+        }
+    }
+}
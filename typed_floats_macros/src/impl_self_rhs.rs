@@ -400,8 +400,108 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
         }));
 
         ops.push(div_euclid.build());
+
+        let rem_euclid = OpRhsBuilder::new("RemEuclid", "rem_euclid");
+
+        #[cfg(feature = "std")]
+        let rem_euclid =
+            rem_euclid.op_fn(Box::new(|_, _| quote! { self.get().rem_euclid(rhs.get()) }));
+        #[cfg(all(feature = "libm", not(feature = "std")))]
+        let rem_euclid = rem_euclid.op_fn(Box::new(
+            |_, _| quote! { num_traits::Euclid::rem_euclid(&self.get(),&rhs.get()) },
+        ));
+
+        #[cfg(feature = "std")]
+        let rem_euclid =
+            rem_euclid.op_test_primitive(Box::new(|var1, var2| quote! { #var1.rem_euclid(#var2) }));
+
+        #[cfg(all(feature = "libm", not(feature = "std")))]
+        let rem_euclid = rem_euclid.op_test_primitive(Box::new(
+            |var1, var2| quote! { num_traits::Euclid::rem_euclid(&#var1, &#var2) },
+        ));
+
+        let rem_euclid = rem_euclid.result(Box::new(|float, rhs| {
+            let spec_a = &float.s;
+            let spec_b = &rhs.s;
+
+            let can_be_nan = spec_a.accept_inf || spec_b.accept_zero;
+
+            if can_be_nan {
+                ReturnTypeSpecification::NativeFloat
+            } else {
+                ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                    // A negative value plus an infinite `rhs.abs()`
+                    accept_inf: spec_a.accept_negative && spec_b.accept_inf,
+                    accept_zero: true,
+                    accept_positive: true,
+                    // Only `-0.0`, when the remainder of a negative value is zero
+                    accept_negative: spec_a.accept_negative,
+                })
+            }
+        }));
+
+        ops.push(rem_euclid.build());
+
+        let log = OpRhsBuilder::new("Log", "log")
+            .op_fn(Box::new(|_, _| quote! { self.get().log(rhs.get()) }))
+            .op_test_primitive(Box::new(|var1, var2| quote! { #var1.log(#var2) }))
+            .comment("`1.0.log(1.0)` is `NaN`, so the result is never a typed float.")
+            .result(Box::new(|_, _| ReturnTypeSpecification::NativeFloat));
+
+        ops.push(log.build());
     };
 
+    let abs_sub = OpRhsBuilder::new("AbsSub", "abs_sub")
+        // `f64::abs_sub` is deprecated
+        .op_fn(Box::new(|_, _| {
+            quote! {
+                if self.get() <= rhs.get() {
+                    0.0
+                } else {
+                    self.get() - rhs.get()
+                }
+            }
+        }));
+
+    #[cfg(feature = "std")]
+    let abs_sub = abs_sub.op_test_primitive(Box::new(|var1, var2| {
+        quote! {
+            {
+                #[allow(deprecated)]
+                let res = #var1.abs_sub(#var2);
+                res
+            }
+        }
+    }));
+
+    #[cfg(not(feature = "std"))]
+    let abs_sub = abs_sub.op_test_primitive(Box::new(|var1, var2| {
+        quote! { if #var1 <= #var2 { 0.0 } else { #var1 - #var2 } }
+    }));
+
+    let abs_sub = abs_sub.result(Box::new(|float, rhs| {
+        let spec_a = &float.s;
+        let spec_b = &rhs.s;
+
+        // `self <= rhs` is always possible unless `self` is positive and `rhs` is negative
+        // or both are zero
+        let can_be_lower_or_equal = spec_a.accept_negative
+            || spec_b.accept_positive
+            || (spec_a.accept_zero && spec_b.accept_zero);
+
+        ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+            // Overflow, or `+inf - rhs`, or `self - -inf`
+            accept_inf: (spec_a.accept_positive && (spec_b.accept_negative || spec_a.accept_inf))
+                || (spec_b.accept_inf && spec_b.accept_negative),
+            accept_zero: can_be_lower_or_equal,
+            // The difference and `0.0` are positive
+            accept_positive: true,
+            accept_negative: false,
+        })
+    }));
+
+    ops.push(abs_sub.build());
+
     if rustversion::cfg!(since(1.85)) {
         let midpoint = OpRhsBuilder::new("Midpoint", "midpoint")
             .op_test_primitive(Box::new(|var1, var2| quote! { #var1.midpoint(#var2) }))
@@ -0,0 +1,174 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::Ident;
+
+use crate::types::{
+    impl_ref_assign, impl_ref_op, output_name, FloatDefinition, OpRhs, ReturnTypeDefinition,
+};
+
+/// The integers, with the strictest type accepting all their values once converted
+const INTEGERS: [(&str, &str); 20] = [
+    ("i8", "NonNaNFinite"),
+    ("i16", "NonNaNFinite"),
+    ("i32", "NonNaNFinite"),
+    ("i64", "NonNaNFinite"),
+    ("isize", "NonNaNFinite"),
+    ("u8", "PositiveFinite"),
+    ("u16", "PositiveFinite"),
+    ("u32", "PositiveFinite"),
+    ("u64", "PositiveFinite"),
+    ("usize", "PositiveFinite"),
+    ("core::num::NonZeroI8", "NonZeroNonNaNFinite"),
+    ("core::num::NonZeroI16", "NonZeroNonNaNFinite"),
+    ("core::num::NonZeroI32", "NonZeroNonNaNFinite"),
+    ("core::num::NonZeroI64", "NonZeroNonNaNFinite"),
+    ("core::num::NonZeroIsize", "NonZeroNonNaNFinite"),
+    ("core::num::NonZeroU8", "StrictlyPositiveFinite"),
+    ("core::num::NonZeroU16", "StrictlyPositiveFinite"),
+    ("core::num::NonZeroU32", "StrictlyPositiveFinite"),
+    ("core::num::NonZeroU64", "StrictlyPositiveFinite"),
+    ("core::num::NonZeroUsize", "StrictlyPositiveFinite"),
+];
+
+/// The operators implemented with integers
+const OPS: [&str; 4] = [
+    "core::ops::Add",
+    "core::ops::Sub",
+    "core::ops::Mul",
+    "core::ops::Div",
+];
+
+/// Generates an operator between a typed float and an integer, in both directions.
+///
+/// The integer is converted into its type, so the result is the one of the operator with that type.
+fn impl_op(
+    op: &OpRhs,
+    float: &FloatDefinition,
+    int: &str,
+    int_float: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let float_full_type = float.full_type_ident();
+    let float_type = float.float_type_ident();
+    let int_float_call = int_float.call_tokens();
+    let int_type: syn::Path = syn::parse_str(int).unwrap();
+    let int_type = quote! { #int_type };
+
+    let trait_ident: syn::Path = syn::parse_str(op.trait_name).unwrap();
+    let fn_ident = Ident::new(op.fn_name, Span::call_site());
+
+    let convert = |value: &Ident| {
+        let int_value = if int.starts_with("core::num::NonZero") {
+            quote! { #value.get() }
+        } else {
+            quote! { #value }
+        };
+
+        quote! {
+            // Safety: the integer is converted into a value accepted by its type
+            #[allow(clippy::cast_lossless)]
+            #[allow(clippy::cast_precision_loss)]
+            let #value = unsafe { #int_float_call::new_unchecked(#int_value as #float_type) };
+        }
+    };
+    let rhs = Ident::new("rhs", Span::call_site());
+    let convert_rhs = convert(&rhs);
+    let lhs = Ident::new("lhs", Span::call_site());
+    let convert_lhs = convert(&lhs);
+
+    let output = op.get_result(float, int_float, floats);
+    let output_lhs_float = output_name(&output, &float_type);
+    let output_lhs_int = output_name(&op.get_result(int_float, float, floats), &float_type);
+
+    let mut res = quote! {
+        impl #trait_ident<#int_type> for #float_full_type {
+            type Output = #output_lhs_float;
+
+            #[inline]
+            fn #fn_ident(self, rhs: #int_type) -> Self::Output {
+                #convert_rhs
+
+                #trait_ident::#fn_ident(self, rhs)
+            }
+        }
+
+        impl #trait_ident<#float_full_type> for #int_type {
+            type Output = #output_lhs_int;
+
+            #[inline]
+            fn #fn_ident(self, rhs: #float_full_type) -> Self::Output {
+                let lhs = self;
+                #convert_lhs
+
+                #trait_ident::#fn_ident(lhs, rhs)
+            }
+        }
+    };
+
+    res.extend(impl_ref_op(
+        &trait_ident,
+        &fn_ident,
+        &float_full_type,
+        &int_type,
+        &output_lhs_float,
+    ));
+    res.extend(impl_ref_op(
+        &trait_ident,
+        &fn_ident,
+        &int_type,
+        &float_full_type,
+        &output_lhs_int,
+    ));
+
+    if let Some((assign_trait, assign_fn)) = &op.assign {
+        if let ReturnTypeDefinition::FloatDefinition(output) = output {
+            if output.s.can_fit_into(&float.s) {
+                let trait_assign_ident: syn::Path = syn::parse_str(assign_trait).unwrap();
+                let fn_assign_ident = Ident::new(assign_fn, Span::call_site());
+
+                res.extend(quote! {
+                    impl #trait_assign_ident<#int_type> for #float_full_type {
+                        #[inline]
+                        fn #fn_assign_ident(&mut self, rhs: #int_type) {
+                            #convert_rhs
+
+                            #trait_assign_ident::#fn_assign_ident(self, rhs);
+                        }
+                    }
+                });
+
+                res.extend(impl_ref_assign(
+                    &trait_assign_ident,
+                    &fn_assign_ident,
+                    &float_full_type,
+                    &int_type,
+                ));
+            }
+        }
+    }
+
+    res
+}
+
+/// Generates the arithmetic operators between the typed floats and the integers.
+pub(crate) fn impl_int_ops(
+    floats: &[FloatDefinition],
+    ops_rhs: &[OpRhs],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    for (int, name) in INTEGERS {
+        let int_float = floats
+            .iter()
+            .find(|float| float.name == name)
+            .expect("The type of the integer is defined");
+
+        for float in floats {
+            for op in ops_rhs.iter().filter(|op| OPS.contains(&op.trait_name)) {
+                output.extend(impl_op(op, float, int, int_float, floats));
+            }
+        }
+    }
+
+    output
+}
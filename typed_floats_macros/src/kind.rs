@@ -0,0 +1,112 @@
+use quote::quote;
+
+use crate::types::FloatDefinition;
+
+/// Generates the `FloatKind` enum and the accessors of its specifications.
+pub(crate) fn generate_float_kind(floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
+    let variants = floats.iter().map(|float| {
+        let name = float.name_ident();
+        let doc = format!(" The kind of [`{}`]", float.name);
+
+        quote! {
+            #[doc = #doc]
+            #name,
+        }
+    });
+
+    let names = floats.iter().map(FloatDefinition::name_ident);
+    let count = floats.len();
+
+    let accessor = |doc: &str, fn_name: &str, accept: fn(&FloatDefinition) -> bool| {
+        let fn_ident = syn::Ident::new(fn_name, proc_macro2::Span::call_site());
+        let arms = floats.iter().map(|float| {
+            let name = float.name_ident();
+            let value = accept(float);
+
+            quote! { Self::#name => #value, }
+        });
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            pub const fn #fn_ident(self) -> bool {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+
+    let accept_infinity = accessor(
+        " Returns true if the kind accepts infinity",
+        "accept_infinity",
+        |float| float.s.accept_inf,
+    );
+    let accept_zero = accessor(
+        " Returns true if the kind accepts zero",
+        "accept_zero",
+        |float| float.s.accept_zero,
+    );
+    let accept_positive = accessor(
+        " Returns true if the kind accepts positive values",
+        "accept_positive",
+        |float| float.s.accept_positive,
+    );
+    let accept_negative = accessor(
+        " Returns true if the kind accepts negative values",
+        "accept_negative",
+        |float| float.s.accept_negative,
+    );
+
+    let from_arms = floats.iter().map(|float| {
+        let name = float.name_ident();
+        let accept_inf = float.s.accept_inf;
+        let accept_zero = float.s.accept_zero;
+        let accept_positive = float.s.accept_positive;
+        let accept_negative = float.s.accept_negative;
+
+        quote! {
+            (#accept_inf, #accept_zero, #accept_positive, #accept_negative) => Some(Self::#name),
+        }
+    });
+
+    quote! {
+        /// The kind of a typed float, to handle the constraints of the types at runtime
+        ///
+        /// The kinds are ordered by inclusion: a kind [can fit into](FloatKind::can_fit_into) another one
+        /// if all the values accepted by the first one are accepted by the second one.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum FloatKind {
+            #(#variants)*
+        }
+
+        impl FloatKind {
+            /// All the kinds
+            pub const ALL: [Self; #count] = [#(Self::#names),*];
+
+            #accept_infinity
+
+            #accept_zero
+
+            #accept_positive
+
+            #accept_negative
+
+            /// Returns the kind accepting exactly those values, if any
+            #[inline]
+            #[must_use]
+            const fn from_accept(
+                accept_inf: bool,
+                accept_zero: bool,
+                accept_positive: bool,
+                accept_negative: bool,
+            ) -> Option<Self> {
+                match (accept_inf, accept_zero, accept_positive, accept_negative) {
+                    #(#from_arms)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
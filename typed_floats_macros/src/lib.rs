@@ -12,6 +12,8 @@ use types::{FloatDefinition, FloatSpecifications, ReturnTypeDefinition};
 
 mod impl_self;
 use impl_self::get_impl_self;
+#[cfg(any(feature = "std", feature = "libm"))]
+use impl_self::get_impl_sin_cos;
 
 mod impl_self_rhs;
 use impl_self_rhs::get_impl_self_rhs;
@@ -19,8 +21,25 @@ use impl_self_rhs::get_impl_self_rhs;
 mod add_doc;
 use add_doc::generate_main_description;
 
+mod common_type;
+use common_type::impl_common_type;
+
 mod gen_tests;
 
+mod int_ops;
+use int_ops::impl_int_ops;
+
+mod kind;
+
+mod literal;
+use literal::{expand_tf, TfInput};
+
+mod mixed_precision;
+use mixed_precision::impl_mixed_precision;
+
+mod refine;
+use refine::impl_refine;
+
 static F32: &str = "f32";
 static F64: &str = "f64";
 
@@ -213,6 +232,26 @@ pub fn generate_docs(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     output.into()
 }
 
+/// Generate the `FloatKind` enum.
+#[proc_macro]
+pub fn generate_float_kind(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let floats_f64 = get_definitions("f64");
+
+    kind::generate_float_kind(&floats_f64).into()
+}
+
+/// Create a constant of the strictest type accepting the literal.
+///
+/// See `typed_floats::tf` for the documentation, which calls it with `$crate;` before the literal.
+#[proc_macro]
+pub fn tf(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as TfInput);
+
+    expand_tf(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Generate the `PartialEq`, `From` and `TryFrom` implementations.
 #[proc_macro]
 pub fn generate_floats(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -223,6 +262,11 @@ pub fn generate_floats(_input: proc_macro::TokenStream) -> proc_macro::TokenStre
 
     output.extend(do_generate_floats(&floats_f64));
     output.extend(do_generate_floats(&floats_f32));
+    output.extend(impl_mixed_precision(
+        &floats_f64,
+        &floats_f32,
+        &get_impl_self_rhs(),
+    ));
 
     output.into()
 }
@@ -273,11 +317,28 @@ fn do_generate_floats(floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
         }
     }
 
+    for float in floats {
+        output.extend(impl_refine(float, floats));
+    }
+
+    for float_a in floats {
+        for float_b in floats {
+            output.extend(impl_common_type(float_a, float_b, floats));
+        }
+    }
+
     for float_a in floats {
         for op in &ops {
             output.extend(op.get_impl(float_a, floats));
         }
 
+        #[cfg(any(feature = "std", feature = "libm"))]
+        output.extend(get_impl_sin_cos(&ops, float_a, floats));
+
+        for op in &ops_rhs {
+            output.extend(op.get_impl_const(float_a, floats));
+        }
+
         for float_b in floats {
             for op in &ops_rhs {
                 output.extend(op.get_impl(float_a, float_b, floats));
@@ -285,5 +346,7 @@ fn do_generate_floats(floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
         }
     }
 
+    output.extend(impl_int_ops(floats, &ops_rhs));
+
     output
 }
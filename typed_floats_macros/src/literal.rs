@@ -0,0 +1,228 @@
+use proc_macro2::{Literal, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, Lit, Path, Token};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Precision {
+    F32,
+    F64,
+}
+
+impl Precision {
+    fn from_ident(ident: &Ident) -> Option<Self> {
+        if ident == "f32" {
+            Some(Self::F32)
+        } else if ident == "f64" {
+            Some(Self::F64)
+        } else {
+            None
+        }
+    }
+
+    fn from_suffix(suffix: &str, span: Span) -> syn::Result<Option<Self>> {
+        match suffix {
+            "" => Ok(None),
+            "f32" => Ok(Some(Self::F32)),
+            "f64" => Ok(Some(Self::F64)),
+            _ => Err(syn::Error::new(
+                span,
+                format!("unsupported suffix `{suffix}`, expected `f32` or `f64`"),
+            )),
+        }
+    }
+
+    fn ident(self) -> Ident {
+        match self {
+            Self::F32 => Ident::new("f32", Span::call_site()),
+            Self::F64 => Ident::new("f64", Span::call_site()),
+        }
+    }
+}
+
+/// The value of the literal, before the sign is applied
+enum Value {
+    /// The decimal digits of a float or integer literal
+    Digits(String),
+    Infinity,
+    NegInfinity,
+}
+
+/// The input of `tf!`: `[f32 | f64 ,] [-] (literal | INFINITY | NEG_INFINITY)`
+pub(crate) struct TfInput {
+    krate: TokenTree,
+    precision: Option<(Precision, Span)>,
+    negative: bool,
+    value: Value,
+    value_precision: Option<Precision>,
+    span: Span,
+}
+
+impl Parse for TfInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `$crate`, given by the `tf!` wrapper of `typed_floats`
+        let krate: TokenTree = input.parse()?;
+        input.parse::<Token![;]>()?;
+
+        let precision = if input.peek(Ident) && input.peek2(Token![,]) {
+            let ident: Ident = input.parse()?;
+            let precision = Precision::from_ident(&ident)
+                .ok_or_else(|| syn::Error::new(ident.span(), "expected `f32` or `f64`"))?;
+            input.parse::<Token![,]>()?;
+            Some((precision, ident.span()))
+        } else {
+            None
+        };
+
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+
+        let (value, value_precision, span) = if input.peek(Lit) {
+            match input.parse::<Lit>()? {
+                Lit::Float(lit) => (
+                    Value::Digits(lit.base10_digits().to_string()),
+                    Precision::from_suffix(lit.suffix(), lit.span())?,
+                    lit.span(),
+                ),
+                Lit::Int(lit) => (
+                    Value::Digits(lit.base10_digits().to_string()),
+                    Precision::from_suffix(lit.suffix(), lit.span())?,
+                    lit.span(),
+                ),
+                lit => return Err(syn::Error::new(lit.span(), "expected a float literal")),
+            }
+        } else {
+            let path: Path = input.parse()?;
+            parse_constant(&path)?
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the literal"));
+        }
+
+        Ok(Self {
+            krate,
+            precision,
+            negative,
+            value,
+            value_precision,
+            span,
+        })
+    }
+}
+
+/// Parses `INFINITY`, `NEG_INFINITY` or `NAN`, optionally prefixed by `f32::` or `f64::`
+fn parse_constant(path: &Path) -> syn::Result<(Value, Option<Precision>, Span)> {
+    let mut segments = path.segments.iter().rev();
+
+    let Some(last) = segments.next() else {
+        unreachable!("a path has at least one segment");
+    };
+
+    let precision =
+        match segments.next() {
+            Some(segment) => Some(Precision::from_ident(&segment.ident).ok_or_else(|| {
+                syn::Error::new_spanned(&segment.ident, "expected `f32` or `f64`")
+            })?),
+            None => None,
+        };
+
+    let value = match last.ident.to_string().as_str() {
+        "INFINITY" => Value::Infinity,
+        "NEG_INFINITY" => Value::NegInfinity,
+        "NAN" => return Err(syn::Error::new_spanned(path, "NaN is not valid")),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                path,
+                "expected a float literal, `INFINITY` or `NEG_INFINITY`",
+            ))
+        }
+    };
+
+    Ok((value, precision, last.ident.span()))
+}
+
+/// Expands `tf!` to a constant of the strictest type accepting the value
+pub(crate) fn expand_tf(input: &TfInput) -> syn::Result<TokenStream> {
+    let precision = match (input.precision, input.value_precision) {
+        (Some((explicit, span)), Some(suffix)) if explicit != suffix => {
+            return Err(syn::Error::new(
+                span,
+                "the precision doesn't match the suffix of the literal",
+            ));
+        }
+        (Some((precision, _)), _) | (None, Some(precision)) => precision,
+        (None, None) => Precision::F64,
+    };
+
+    let (is_infinite, is_zero, is_negative, literal) = match &input.value {
+        Value::Digits(digits) => {
+            // The literal is only built when finite, as infinities have no literal
+            let (is_zero, literal) = match precision {
+                Precision::F32 => {
+                    let value: f32 = digits
+                        .parse()
+                        .map_err(|_| syn::Error::new(input.span, "invalid float literal"))?;
+                    (
+                        value == 0.0,
+                        value.is_finite().then(|| Literal::f32_unsuffixed(value)),
+                    )
+                }
+                Precision::F64 => {
+                    let value: f64 = digits
+                        .parse()
+                        .map_err(|_| syn::Error::new(input.span, "invalid float literal"))?;
+                    (
+                        value == 0.0,
+                        value.is_finite().then(|| Literal::f64_unsuffixed(value)),
+                    )
+                }
+            };
+
+            let Some(literal) = literal else {
+                let float = precision.ident();
+                return Err(syn::Error::new(
+                    input.span,
+                    format!("the literal overflows to infinity in {float}, use `INFINITY` instead"),
+                ));
+            };
+
+            // Only the digits before the exponent can make the literal non-zero
+            let mantissa = digits.split(['e', 'E']).next().unwrap_or_default();
+            if is_zero && mantissa.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+                let float = precision.ident();
+                return Err(syn::Error::new(
+                    input.span,
+                    format!("the literal underflows to zero in {float}"),
+                ));
+            }
+
+            (false, is_zero, input.negative, Some(literal))
+        }
+        Value::Infinity => (true, false, input.negative, None),
+        Value::NegInfinity => (true, false, !input.negative, None),
+    };
+
+    let kind = match (is_negative, is_zero, is_infinite) {
+        (false, false, false) => "StrictlyPositiveFinite",
+        (false, false, true) => "StrictlyPositive",
+        (false, true, _) => "PositiveFinite",
+        (true, true, _) => "NegativeFinite",
+        (true, false, false) => "StrictlyNegativeFinite",
+        (true, false, true) => "StrictlyNegative",
+    };
+    let kind = Ident::new(kind, input.span);
+    let float = precision.ident();
+
+    let value = match (literal, is_negative) {
+        (Some(literal), false) => quote! { #literal },
+        (Some(literal), true) => quote! { -#literal },
+        (None, false) => quote! { #float::INFINITY },
+        (None, true) => quote! { #float::NEG_INFINITY },
+    };
+
+    let krate = &input.krate;
+
+    Ok(quote! {
+        #krate::as_const!(#kind, #float, #value)
+    })
+}
@@ -0,0 +1,156 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::Ident;
+
+use crate::types::{
+    impl_ref_assign, impl_ref_op, output_name, FloatDefinition, OpRhs, ReturnTypeDefinition,
+};
+
+/// Generates the `PartialEq` and `PartialOrd` implementations between an `f64` type and an `f32` type.
+fn impl_cmp(a_f64: &FloatDefinition, b_f32: &FloatDefinition) -> proc_macro2::TokenStream {
+    let a_full_type = a_f64.full_type_ident();
+    let b_full_type = b_f32.full_type_ident();
+
+    quote! {
+        impl PartialEq<#b_full_type> for #a_full_type {
+            #[inline]
+            fn eq(&self, other: &#b_full_type) -> bool {
+                self.0 == f64::from(other.0)
+            }
+        }
+
+        impl PartialEq<#a_full_type> for #b_full_type {
+            #[inline]
+            fn eq(&self, other: &#a_full_type) -> bool {
+                f64::from(self.0) == other.0
+            }
+        }
+
+        impl PartialOrd<#b_full_type> for #a_full_type {
+            #[inline]
+            fn partial_cmp(&self, other: &#b_full_type) -> Option<core::cmp::Ordering> {
+                self.0.partial_cmp(&f64::from(other.0))
+            }
+        }
+
+        impl PartialOrd<#a_full_type> for #b_full_type {
+            #[inline]
+            fn partial_cmp(&self, other: &#a_full_type) -> Option<core::cmp::Ordering> {
+                f64::from(self.0).partial_cmp(&other.0)
+            }
+        }
+    }
+}
+
+/// Generates a binary operator between an `f64` type and an `f32` type, in both directions.
+///
+/// The `f32` operand is widened into the `f64` type of the same kind, so the result is the one of the `f64` operator.
+fn impl_op(
+    op: &OpRhs,
+    a_f64: &FloatDefinition,
+    b_f64: &FloatDefinition,
+    b_f32: &FloatDefinition,
+    floats_f64: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let output = op.get_result(a_f64, b_f64, floats_f64);
+    let output_lhs_f64 = output_name(&output, &a_f64.float_type_ident());
+
+    let a_f64_type = a_f64.full_type_ident();
+    let b_f64_call = b_f64.call_tokens();
+    let b_f32_type = b_f32.full_type_ident();
+
+    let trait_ident: syn::Path = syn::parse_str(op.trait_name).unwrap();
+    let fn_ident = Ident::new(op.fn_name, Span::call_site());
+
+    let mut res = quote! {
+        impl #trait_ident<#b_f32_type> for #a_f64_type {
+            type Output = #output_lhs_f64;
+
+            #[inline]
+            fn #fn_ident(self, rhs: #b_f32_type) -> Self::Output {
+                #trait_ident::#fn_ident(self, #b_f64_call::from(rhs))
+            }
+        }
+    };
+
+    let output_lhs_f32 = output_name(
+        &op.get_result(b_f64, a_f64, floats_f64),
+        &a_f64.float_type_ident(),
+    );
+
+    res.extend(quote! {
+        impl #trait_ident<#a_f64_type> for #b_f32_type {
+            type Output = #output_lhs_f32;
+
+            #[inline]
+            fn #fn_ident(self, rhs: #a_f64_type) -> Self::Output {
+                #trait_ident::#fn_ident(#b_f64_call::from(self), rhs)
+            }
+        }
+    });
+
+    res.extend(impl_ref_op(
+        &trait_ident,
+        &fn_ident,
+        &a_f64_type,
+        &b_f32_type,
+        &output_lhs_f64,
+    ));
+    res.extend(impl_ref_op(
+        &trait_ident,
+        &fn_ident,
+        &b_f32_type,
+        &a_f64_type,
+        &output_lhs_f32,
+    ));
+
+    // Only the `f64` side can be assigned, the result doesn't fit into an `f32`
+    if let Some((assign_trait, assign_fn)) = &op.assign {
+        if let ReturnTypeDefinition::FloatDefinition(output) = output {
+            if output.s.can_fit_into(&a_f64.s) {
+                let trait_assign_ident: syn::Path = syn::parse_str(assign_trait).unwrap();
+                let fn_assign_ident = Ident::new(assign_fn, Span::call_site());
+
+                res.extend(quote! {
+                    impl #trait_assign_ident<#b_f32_type> for #a_f64_type {
+                        #[inline]
+                        fn #fn_assign_ident(&mut self, rhs: #b_f32_type) {
+                            #trait_assign_ident::#fn_assign_ident(self, #b_f64_call::from(rhs));
+                        }
+                    }
+                });
+
+                res.extend(impl_ref_assign(
+                    &trait_assign_ident,
+                    &fn_assign_ident,
+                    &a_f64_type,
+                    &b_f32_type,
+                ));
+            }
+        }
+    }
+
+    res
+}
+
+/// Generates the comparisons and the binary operators between the `f64` and the `f32` types.
+pub(crate) fn impl_mixed_precision(
+    floats_f64: &[FloatDefinition],
+    floats_f32: &[FloatDefinition],
+    ops_rhs: &[OpRhs],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    for a_f64 in floats_f64 {
+        // The types are defined in the same order for both precisions
+        for (b_f64, b_f32) in floats_f64.iter().zip(floats_f32) {
+            output.extend(impl_cmp(a_f64, b_f32));
+
+            for op in ops_rhs.iter().filter(|op| op.is_bin_op) {
+                output.extend(impl_op(op, a_f64, b_f64, b_f32, floats_f64));
+            }
+        }
+    }
+
+    output
+}
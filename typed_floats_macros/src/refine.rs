@@ -0,0 +1,195 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::Ident;
+
+use crate::types::{FloatDefinition, FloatSpecifications};
+
+/// Returns the type accepting exactly the values described by the specifications
+fn find_exact<'a>(
+    s: &FloatSpecifications,
+    floats: &'a [FloatDefinition],
+) -> Option<&'a FloatDefinition> {
+    floats.iter().find(|float| {
+        float.s.accept_inf == s.accept_inf
+            && float.s.accept_zero == s.accept_zero
+            && float.s.accept_positive == s.accept_positive
+            && float.s.accept_negative == s.accept_negative
+    })
+}
+
+/// Returns the type of the rejected value and the expression building it from `self.0`
+fn rejected(
+    s: &FloatSpecifications,
+    positive: &str,
+    negative: &str,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let positive = Ident::new(positive, Span::call_site());
+    let negative = Ident::new(negative, Span::call_site());
+
+    match (s.accept_positive, s.accept_negative) {
+        (true, true) => (
+            quote! { crate::Signed<crate::#positive, crate::#negative> },
+            quote! {
+                if self.0.is_sign_positive() {
+                    crate::Signed::Positive(crate::#positive)
+                } else {
+                    crate::Signed::Negative(crate::#negative)
+                }
+            },
+        ),
+        (true, false) => (quote! { crate::#positive }, quote! { crate::#positive }),
+        (false, true) => (quote! { crate::#negative }, quote! { crate::#negative }),
+        (false, false) => unreachable!("All types accept at least one sign"),
+    }
+}
+
+/// Generates `refine_zero`, returning the value without zero or the rejected zero.
+fn refine_zero(float: &FloatDefinition, floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
+    if !float.s.accept_zero {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let refined = find_exact(
+        &FloatSpecifications {
+            accept_zero: false,
+            ..float.s.clone()
+        },
+        floats,
+    )
+    .expect("All types have a non-zero counterpart");
+
+    let refined_call = refined.call_tokens();
+    let refined_type = refined.full_type_ident();
+    let (error_type, error) = rejected(&float.s, "PositiveZero", "NegativeZero");
+    let doc = format!(
+        " Returns the value as [`{}`] if it is not zero",
+        refined.name
+    );
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// # Errors
+        /// Returns the zero if the value is zero
+        #[inline]
+        #[const_fn("1.83")]
+        pub const fn refine_zero(self) -> Result<#refined_type, #error_type> {
+            if self.0 == 0.0 {
+                Err(#error)
+            } else {
+                // Safety: the value is accepted by `Self` and is not zero
+                Ok(unsafe { #refined_call::internal_only_new_unchecked(self.0) })
+            }
+        }
+    }
+}
+
+/// Generates `refine_finite`, returning the finite value or the rejected infinity.
+fn refine_finite(float: &FloatDefinition, floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
+    if !float.s.accept_inf {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let refined = find_exact(
+        &FloatSpecifications {
+            accept_inf: false,
+            ..float.s.clone()
+        },
+        floats,
+    )
+    .expect("All types have a finite counterpart");
+
+    let refined_call = refined.call_tokens();
+    let refined_type = refined.full_type_ident();
+    let (error_type, error) = rejected(&float.s, "PositiveInfinity", "NegativeInfinity");
+    let doc = format!(" Returns the value as [`{}`] if it is finite", refined.name);
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// # Errors
+        /// Returns the infinity if the value is infinite
+        #[inline]
+        #[const_fn("1.83")]
+        pub const fn refine_finite(self) -> Result<#refined_type, #error_type> {
+            if self.0.is_infinite() {
+                Err(#error)
+            } else {
+                // Safety: the value is accepted by `Self` and is finite
+                Ok(unsafe { #refined_call::internal_only_new_unchecked(self.0) })
+            }
+        }
+    }
+}
+
+/// Generates `split_sign`, returning the value in the positive or the negative type.
+fn split_sign(float: &FloatDefinition, floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
+    if !(float.s.accept_positive && float.s.accept_negative) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let positive = find_exact(
+        &FloatSpecifications {
+            accept_negative: false,
+            ..float.s.clone()
+        },
+        floats,
+    )
+    .expect("All types have a positive counterpart");
+    let negative = find_exact(
+        &FloatSpecifications {
+            accept_positive: false,
+            ..float.s.clone()
+        },
+        floats,
+    )
+    .expect("All types have a negative counterpart");
+
+    let positive_call = positive.call_tokens();
+    let positive_type = positive.full_type_ident();
+    let negative_call = negative.call_tokens();
+    let negative_type = negative.full_type_ident();
+    let doc = format!(
+        " Returns the value as [`{}`] if its sign is positive, or as [`{}`] otherwise",
+        positive.name, negative.name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #[const_fn("1.83")]
+        pub const fn split_sign(self) -> crate::Signed<#positive_type, #negative_type> {
+            // Safety: the value is accepted by `Self` and has the sign of the type
+            unsafe {
+                if self.0.is_sign_positive() {
+                    crate::Signed::Positive(#positive_call::internal_only_new_unchecked(self.0))
+                } else {
+                    crate::Signed::Negative(#negative_call::internal_only_new_unchecked(self.0))
+                }
+            }
+        }
+    }
+}
+
+/// Generates the refinement methods, splitting a type into its sub-types.
+pub(crate) fn impl_refine(
+    float: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let full_type = float.full_type_ident();
+
+    let refine_zero = refine_zero(float, floats);
+    let refine_finite = refine_finite(float, floats);
+    let split_sign = split_sign(float, floats);
+
+    quote! {
+        impl #full_type {
+            #refine_zero
+
+            #refine_finite
+
+            #split_sign
+        }
+    }
+}
@@ -24,14 +24,69 @@ fn impl_try_from(
     let from_full_type = &float_from.full_type_ident();
     let to_full_type = &float_to.full_type_ident();
 
+    let from = &float_from.s;
+    let to = &float_to.s;
+
+    // The checks are done in the same order as `new`, so a value is rejected for the same reason
+    let infinite = from.accept_inf && !to.accept_inf;
+    let negative = from.accept_negative && !to.accept_negative;
+    let positive = from.accept_positive && !to.accept_positive;
+    let zero = from.accept_zero
+        && !to.accept_zero
+        && ((from.accept_positive && to.accept_positive)
+            || (from.accept_negative && to.accept_negative));
+
+    let reason = |possible: bool| {
+        if possible {
+            quote! { crate::Possible }
+        } else {
+            quote! { crate::Impossible }
+        }
+    };
+    let reasons = [infinite, negative, positive, zero].map(reason);
+
+    let mut checks = proc_macro2::TokenStream::new();
+    if infinite {
+        checks.extend(quote! {
+            if value.is_infinite() {
+                return Err(crate::Rejected::Infinite(crate::Possible));
+            }
+        });
+    }
+    if negative {
+        checks.extend(quote! {
+            if value.is_sign_negative() {
+                return Err(crate::Rejected::Negative(crate::Possible));
+            }
+        });
+    }
+    if positive {
+        checks.extend(quote! {
+            if value.is_sign_positive() {
+                return Err(crate::Rejected::Positive(crate::Possible));
+            }
+        });
+    }
+    if zero {
+        checks.extend(quote! {
+            if value == 0.0 {
+                return Err(crate::Rejected::Zero(crate::Possible));
+            }
+        });
+    }
+
     quote! {
         impl core::convert::TryFrom<#from_full_type> for #to_full_type {
-            type Error = InvalidNumber;
+            type Error = crate::Rejected<#(#reasons),*>;
 
             #[inline]
-            #[must_use]
             fn try_from(value: #from_full_type) -> Result<Self, Self::Error> {
-                Self::try_from(value.get())
+                let value = value.get();
+
+                #checks
+
+                // Safety: all the values of the source type rejected by `Self` have been checked
+                Ok(unsafe { Self::new_unchecked(value) })
             }
         }
     }
@@ -143,6 +143,63 @@ pub fn output_name(output: &ReturnTypeDefinition, float_type: &Ident) -> proc_ma
     }
 }
 
+/// Generates the implementations of a binary operator on references (`&a + b`, `a + &b` and `&a + &b`),
+/// forwarding to the implementation on values of the `Copy` operands.
+pub(crate) fn impl_ref_op(
+    trait_ident: &syn::Path,
+    fn_ident: &Ident,
+    lhs_type: &proc_macro2::TokenStream,
+    rhs_type: &proc_macro2::TokenStream,
+    output_name: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        impl #trait_ident<&#rhs_type> for #lhs_type {
+            type Output = #output_name;
+
+            #[inline]
+            fn #fn_ident(self, rhs: &#rhs_type) -> Self::Output {
+                #trait_ident::#fn_ident(self, *rhs)
+            }
+        }
+
+        impl #trait_ident<#rhs_type> for &#lhs_type {
+            type Output = #output_name;
+
+            #[inline]
+            fn #fn_ident(self, rhs: #rhs_type) -> Self::Output {
+                #trait_ident::#fn_ident(*self, rhs)
+            }
+        }
+
+        impl #trait_ident<&#rhs_type> for &#lhs_type {
+            type Output = #output_name;
+
+            #[inline]
+            fn #fn_ident(self, rhs: &#rhs_type) -> Self::Output {
+                #trait_ident::#fn_ident(*self, *rhs)
+            }
+        }
+    }
+}
+
+/// Generates the implementation of an assignment with a reference (`a += &b`),
+/// forwarding to the implementation with the `Copy` value.
+pub(crate) fn impl_ref_assign(
+    trait_assign_ident: &syn::Path,
+    fn_assign_ident: &Ident,
+    lhs_type: &proc_macro2::TokenStream,
+    rhs_type: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        impl #trait_assign_ident<&#rhs_type> for #lhs_type {
+            #[inline]
+            fn #fn_assign_ident(&mut self, rhs: &#rhs_type) {
+                #trait_assign_ident::#fn_assign_ident(self, *rhs);
+            }
+        }
+    }
+}
+
 type OpCallback = Box<dyn Fn(&FloatDefinition) -> proc_macro2::TokenStream>;
 type SimpleResultCallback = Box<dyn Fn(&FloatDefinition) -> ReturnTypeSpecification>;
 type ResultCallback = Box<dyn Fn(&FloatDefinition, &[FloatDefinition]) -> ReturnTypeDefinition>;
@@ -154,11 +211,13 @@ pub struct Op {
     pub(crate) fn_name: &'static str,
     pub(crate) trait_name: Option<&'static str>,
     pub(crate) const_since: Option<&'static str>,
+    pub(crate) const_counterpart_since: Option<&'static str>,
     pub(crate) comment: Option<&'static str>,
     pub(crate) params: proc_macro2::TokenStream,
     pub(crate) description: proc_macro2::TokenStream,
     pub(crate) skip_check_return_type_strictness: bool,
     op: OpCallback,
+    const_op: Option<OpCallback>,
     result: ResultCallback,
     test: TestCallback,
 }
@@ -179,10 +238,12 @@ impl OpBuilder {
                 params: quote! { self },
                 trait_name: None,
                 const_since: None,
+                const_counterpart_since: None,
                 description: proc_macro2::TokenStream::new(),
                 comment: None,
                 skip_check_return_type_strictness: false,
                 op: Box::new(move |_| quote! { self.get().#fn_op() }),
+                const_op: None,
                 result: Box::new(|_, _| panic!("No result defined")),
                 test: Box::new(move |var| quote! { #var.#fn_test() }),
             },
@@ -247,6 +308,15 @@ impl OpBuilder {
         self
     }
 
+    /// Generates a `const_` counterpart, `const` since `version`.
+    /// Its value is computed by `op`, or by the callback of `op_fn` if `None`.
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub fn const_counterpart(mut self, version: &'static str, op: Option<OpCallback>) -> Self {
+        self.op.const_counterpart_since = Some(version);
+        self.op.const_op = op;
+        self
+    }
+
     pub fn build(self) -> Op {
         self.op
     }
@@ -279,9 +349,7 @@ impl Op {
 
         let float_full_type = &float.full_type_ident();
 
-        let op = &self.get_op(float);
-
-        let return_value = match &output {
+        let return_value_of = |op: &proc_macro2::TokenStream| match &output {
             ReturnTypeDefinition::FloatDefinition(d) => {
                 let output_call = &d.call_tokens();
 
@@ -294,6 +362,8 @@ impl Op {
             }
         };
 
+        let return_value = return_value_of(&self.get_op(float));
+
         let output_name = output_name(&output, &float.float_type_ident());
 
         let fn_ident = Ident::new(self.fn_name, Span::call_site());
@@ -313,6 +383,13 @@ impl Op {
         };
 
         if let Some(trait_name) = &self.trait_name {
+            // Trait methods can't be `const`, so an inherent counterpart is generated
+            let const_fn_ident = Ident::new(&format!("const_{}", self.fn_name), Span::call_site());
+            let const_doc = format!(
+                " Same as [`{trait_name}::{}`], but usable in constants.",
+                self.fn_name
+            );
+
             let trait_name: proc_macro2::TokenStream = trait_name.parse().unwrap();
 
             quote! {
@@ -327,8 +404,42 @@ impl Op {
                         #return_value
                     }
                 }
+
+                impl #float_full_type {
+                    #[doc = #const_doc]
+                    #[inline]
+                    #[must_use]
+                    #[const_fn("1.83")]
+                    pub const fn #const_fn_ident(#params) -> #output_name {
+                        #return_value
+                    }
+                }
             }
         } else {
+            let const_counterpart = self.const_counterpart_since.map(|version| {
+                let const_fn_ident =
+                    Ident::new(&format!("const_{}", self.fn_name), Span::call_site());
+                let const_doc = format!(
+                    " Same as [`Self::{}`], but usable in constants.",
+                    self.fn_name
+                );
+
+                let const_return_value = match &self.const_op {
+                    Some(const_op) => return_value_of(&const_op(float)),
+                    None => return_value.clone(),
+                };
+
+                quote! {
+                    #[doc = #const_doc]
+                    #[inline]
+                    #[must_use]
+                    #[const_fn(#version)]
+                    pub const fn #const_fn_ident(#params) -> #output_name {
+                        #const_return_value
+                    }
+                }
+            });
+
             quote! {
                 impl #float_full_type {
                     #description
@@ -338,6 +449,8 @@ impl Op {
                     pub fn #fn_ident(#params) -> #output_name {
                         #return_value
                     }
+
+                    #const_counterpart
                 }
             }
         }
@@ -371,6 +484,7 @@ impl OpRhsBuilder {
                 fn_name,
                 trait_name,
                 assign: None,
+                is_bin_op: false,
                 op_is_commutative: false,
                 skip_check_return_type_strictness: false,
                 comment: None,
@@ -392,6 +506,7 @@ impl OpRhsBuilder {
 
     pub(crate) fn bin_op(mut self, bin_op: &'static str) -> Self {
         self.op.display = bin_op;
+        self.op.is_bin_op = true;
 
         let op_token: syn::BinOp = syn::parse_str(bin_op).unwrap();
         let op_token2 = op_token;
@@ -460,6 +575,7 @@ pub struct OpRhs {
     pub(crate) fn_name: &'static str,
     pub(crate) trait_name: &'static str,
     pub(crate) assign: Option<(&'static str, &'static str)>,
+    pub(crate) is_bin_op: bool,
     pub(crate) op_is_commutative: bool,
     pub(crate) skip_check_return_type_strictness: bool,
     pub(crate) comment: Option<&'static str>,
@@ -541,6 +657,14 @@ impl OpRhs {
             }
         };
 
+        res.extend(impl_ref_op(
+            &trait_ident,
+            &fn_ident,
+            float_full_type,
+            rhs_full_type,
+            &output_name,
+        ));
+
         if let Some((assign_trait, assign_fn)) = &self.assign {
             if let ReturnTypeDefinition::FloatDefinition(output) = output {
                 if output.s.can_fit_into(&float.s) {
@@ -557,10 +681,75 @@ impl OpRhs {
                             }
                         }
                     });
+
+                    res.extend(impl_ref_assign(
+                        &trait_assign_ident,
+                        &fn_assign_ident,
+                        float_full_type,
+                        rhs_full_type,
+                    ));
                 }
             }
         }
 
         res
     }
+
+    /// Generates the `const` counterpart of a binary operator, with `Self` as right-hand side.
+    pub(crate) fn get_impl_const(
+        &self,
+        float: &FloatDefinition,
+        floats: &[FloatDefinition],
+    ) -> proc_macro2::TokenStream {
+        if !self.is_bin_op {
+            return proc_macro2::TokenStream::new();
+        }
+
+        let output = self.get_result(float, float, floats);
+
+        let float_full_type = &float.full_type_ident();
+
+        let op = &self.get_op(float, float);
+
+        let return_value = match &output {
+            ReturnTypeDefinition::FloatDefinition(d) => {
+                let output_call = &d.call_tokens();
+
+                quote! {
+                    unsafe { #output_call::new_unchecked(#op) }
+                }
+            }
+            ReturnTypeDefinition::NativeFloat => {
+                quote! { #op }
+            }
+        };
+
+        let output_name = output_name(&output, &float.float_type_ident());
+
+        let const_fn_ident = Ident::new(&format!("const_{}", self.fn_name), Span::call_site());
+        let doc = format!(
+            " Same as [`{}::{}`] with `Self` as right-hand side, but usable in constants.",
+            self.trait_name, self.fn_name
+        );
+        let limitation_doc = format!(
+            " Unlike [`{}::{}`], the right-hand side can only be `Self`, not another type of this crate nor a primitive.",
+            self.trait_name, self.fn_name
+        );
+        let workaround_doc = " Another type can be converted first with [`as_const!`](crate::as_const), like `as_const!(NonNaN, x.get())`.";
+
+        quote! {
+            impl #float_full_type {
+                #[doc = #doc]
+                #[doc = ""]
+                #[doc = #limitation_doc]
+                #[doc = #workaround_doc]
+                #[inline]
+                #[must_use]
+                #[const_fn("1.83")]
+                pub const fn #const_fn_ident(self, rhs: Self) -> #output_name {
+                    #return_value
+                }
+            }
+        }
+    }
 }